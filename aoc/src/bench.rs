@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use shared::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::process::exit;
+use std::time::Duration;
+
+/// Summary statistics of a series of timings, in milliseconds.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Stats {
+        let mut ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        ms.sort_by(f64::total_cmp);
+        let n = ms.len() as f64;
+        let mean = ms.iter().sum::<f64>() / n;
+        let variance = ms.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let mid = ms.len() / 2;
+        let median = if ms.len().is_multiple_of(2) {
+            (ms[mid - 1] + ms[mid]) / 2.0
+        } else {
+            ms[mid]
+        };
+        Stats {
+            min: ms[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Saved benchmark results, keyed by `dayN/<input key>/<phase>` where phase is `parse` or `partN`.
+type Baseline = BTreeMap<String, Stats>;
+
+fn load_baseline(path: &str) -> AocResult<Baseline> {
+    match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text)
+            .map_err(|err| AocError::at(err.line(), err.column(), err.to_string()).with_file(path)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(err) => Err(AocError::io("read", path, err)),
+    }
+}
+
+fn save_baseline(path: &str, baseline: &Baseline) -> AocResult<()> {
+    let text = serde_json::to_string_pretty(baseline).unwrap();
+    fs::write(path, text + "\n").map_err(|err| AocError::io("write", path, err))
+}
+
+/// Baseline median and the change of the median against it, as shown in the table.
+fn compare(stats: &Stats, base: Option<&Stats>) -> (String, String) {
+    match base {
+        Some(base) => (
+            format!("{:.3}", base.median),
+            format!("{:+.1}%", (stats.median / base.median - 1.0) * 100.0),
+        ),
+        None => ("-".to_string(), "-".to_string()),
+    }
+}
+
+fn exit_with(err: AocError, source: Option<&str>) -> ! {
+    eprintln!("{}", err.render(source));
+    exit(1);
+}
+
+/// Benchmark the day and parts selected by `args`, printing timing statistics for parsing
+/// and each part next to the saved baseline.
+pub fn bench(registry: &Registry, args: &BenchArgs) {
+    let Some(solution) = registry.get(args.day) else {
+        eprintln!("error: day {} is not implemented", args.day);
+        exit(1);
+    };
+    if args.iterations == 0 {
+        eprintln!("error: at least one iteration is needed");
+        exit(1);
+    }
+    let parts: Vec<Part> = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let input = args
+        .input
+        .read(args.day)
+        .unwrap_or_else(|err| exit_with(err, None));
+    let mut baseline = load_baseline(&args.baseline).unwrap_or_else(|err| exit_with(err, None));
+
+    let mut parse_samples = Vec::with_capacity(args.iterations);
+    let mut part_samples: Vec<Vec<Duration>> =
+        vec![Vec::with_capacity(args.iterations); parts.len()];
+    for i in 0..args.warmup + args.iterations {
+        let day_run = solution
+            .run(&input.text, &parts)
+            .unwrap_or_else(|err| exit_with(err.with_file(&input.name), Some(&input.text)));
+        if i < args.warmup {
+            continue;
+        }
+        parse_samples.push(day_run.parse_elapsed);
+        for (samples, answer) in part_samples.iter_mut().zip(&day_run.answers) {
+            samples.push(answer.elapsed);
+        }
+    }
+
+    let mut results = vec![("parse".to_string(), Stats::from_samples(&parse_samples))];
+    for (part, samples) in parts.iter().zip(&part_samples) {
+        results.push((format!("part{}", part), Stats::from_samples(samples)));
+    }
+
+    println!(
+        "Day {}, {} iterations after {} warm-up runs, times in ms",
+        args.day, args.iterations, args.warmup
+    );
+    println!(
+        "{:<6} {:>10} {:>10} {:>10} {:>10} {:>10} {:>8}",
+        "Phase", "Min", "Median", "Mean", "Stddev", "Baseline", "Change"
+    );
+    let input_key = input.key.as_deref().unwrap_or("stdin");
+    let baseline_key = |phase: &str| format!("day{}/{}/{}", args.day, input_key, phase);
+    for (phase, stats) in &results {
+        let (base, change) = compare(stats, baseline.get(&baseline_key(phase)));
+        println!(
+            "{:<6} {:>10.3} {:>10.3} {:>10.3} {:>10.3} {:>10} {:>8}",
+            phase, stats.min, stats.median, stats.mean, stats.stddev, base, change
+        );
+    }
+
+    if args.save {
+        for (phase, stats) in results {
+            baseline.insert(baseline_key(&phase), stats);
+        }
+        save_baseline(&args.baseline, &baseline).unwrap_or_else(|err| exit_with(err, None));
+        println!("Saved baseline to {}", args.baseline);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(ms: &[u64]) -> Stats {
+        let samples: Vec<Duration> = ms.iter().map(|&ms| Duration::from_millis(ms)).collect();
+        Stats::from_samples(&samples)
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn stats_of_odd_and_even_sample_counts() {
+        let odd = stats(&[5, 1, 3]);
+        assert_close(odd.min, 1.0);
+        assert_close(odd.median, 3.0);
+        assert_close(odd.mean, 3.0);
+        assert_close(odd.stddev, (8.0f64 / 3.0).sqrt());
+
+        let even = stats(&[8, 2, 4, 6]);
+        assert_close(even.min, 2.0);
+        assert_close(even.median, 5.0);
+        assert_close(even.mean, 5.0);
+        assert_close(even.stddev, 5.0f64.sqrt());
+
+        let single = stats(&[7]);
+        assert_close(single.median, 7.0);
+        assert_close(single.stddev, 0.0);
+    }
+
+    #[test]
+    fn compare_medians_with_the_baseline() {
+        let base = stats(&[4]);
+        assert_eq!(
+            compare(&stats(&[5]), Some(&base)),
+            ("4.000".to_string(), "+25.0%".to_string())
+        );
+        assert_eq!(
+            compare(&stats(&[3]), Some(&base)),
+            ("4.000".to_string(), "-25.0%".to_string())
+        );
+        assert_eq!(
+            compare(&stats(&[5]), None),
+            ("-".to_string(), "-".to_string())
+        );
+    }
+}
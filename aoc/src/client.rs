@@ -0,0 +1,134 @@
+use crate::config::Config;
+use shared::*;
+use std::time::Duration;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_YEAR: u32 = 2024;
+
+/// User-Agent of every request, so the website operator can tell who is making them.
+fn user_agent(contact: Option<&str>) -> String {
+    let agent = format!("aoc2024-runner/{}", env!("CARGO_PKG_VERSION"));
+    match contact {
+        Some(contact) => format!("{} (contact: {})", agent, contact),
+        None => agent,
+    }
+}
+
+/// Blocking HTTP client for the pages of one Advent of Code event.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    year: u32,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&user_agent(config.contact.as_deref()))
+            .timeout(Duration::from_secs(30))
+            .build();
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year: config.year.unwrap_or(DEFAULT_YEAR),
+            session: config.session.clone(),
+        }
+    }
+
+    /// Client configured by the config file, environment and command line of `remote`.
+    pub fn from_args(remote: &RemoteArgs) -> AocResult<Client> {
+        Ok(Client::new(&Config::resolve(remote)?))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// URL of `path` within the event, like `day/1/input`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}/{}", self.base_url, self.year, path)
+    }
+
+    fn require_session(&self) -> AocResult<()> {
+        match self.session {
+            Some(_) => Ok(()),
+            None => Err(AocError::new(
+                "no session token, set `session` in the config file or AOC_SESSION",
+            )),
+        }
+    }
+
+    fn request(&self, method: &str, path: &str) -> (String, ureq::Request) {
+        let url = self.url(path);
+        let mut request = self.agent.request(method, &url);
+        if let Some(session) = &self.session {
+            request = request.set("Cookie", &format!("session={}", session));
+        }
+        (url, request)
+    }
+
+    /// Body of the page at `path`, sending the session cookie if there is one.
+    pub fn get(&self, path: &str) -> AocResult<String> {
+        let (url, request) = self.request("GET", path);
+        response_text(&url, request.call())
+    }
+
+    /// Puzzle input of `day`, exactly as served. Inputs differ per user, so this needs a
+    /// session.
+    pub fn puzzle_input(&self, day: u32) -> AocResult<String> {
+        self.require_session()?;
+        self.get(&format!("day/{}/input", day))
+    }
+
+    /// Page of the puzzle of `day`. Part 2 is only on it with the session of a user who
+    /// solved part 1.
+    pub fn puzzle_page(&self, day: u32) -> AocResult<String> {
+        self.get(&format!("day/{}", day))
+    }
+
+    /// JSON of the private leaderboard `id`, which needs the session of one of its members.
+    pub fn leaderboard(&self, id: &str) -> AocResult<String> {
+        self.require_session()?;
+        self.get(&format!("leaderboard/private/view/{}.json", id))
+    }
+
+    /// Submit `answer` to `part` of `day`, returning the page the website answers with.
+    pub fn submit_answer(&self, day: u32, part: Part, answer: &Answer) -> AocResult<String> {
+        self.require_session()?;
+        let (url, request) = self.request("POST", &format!("day/{}/answer", day));
+        let level = part.number().to_string();
+        let answer = answer.to_string();
+        response_text(
+            &url,
+            request.send_form(&[("level", &level), ("answer", &answer)]),
+        )
+    }
+}
+
+fn response_text(url: &str, result: Result<ureq::Response, ureq::Error>) -> AocResult<String> {
+    match result {
+        Ok(response) => response.into_string().map_err(|err| {
+            AocError::new(format!("failed to read the response of {}: {}", url, err))
+        }),
+        Err(ureq::Error::Status(status, response)) => {
+            let hint = match status {
+                400 => " (is the session token valid?)",
+                404 => " (is the puzzle unlocked yet?)",
+                _ => "",
+            };
+            let body = response.into_string().unwrap_or_default();
+            let mut message = format!("{} returned status {}{}", url, status, hint);
+            if let Some(line) = body.lines().map(str::trim).find(|line| !line.is_empty()) {
+                message = format!("{}: {}", message, line);
+            }
+            Err(AocError::new(message))
+        }
+        Err(err) => Err(AocError::new(format!("request to {} failed: {}", url, err))),
+    }
+}
@@ -0,0 +1,64 @@
+use serde::Deserialize;
+use shared::*;
+use std::env;
+use std::fs;
+use std::io;
+
+/// Settings for talking to the Advent of Code website, as stored in `aoc.toml`:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// contact = "you@example.com"
+/// leaderboard = "123456"
+/// ```
+///
+/// The file is optional. `AOC_SESSION` and `AOC_BASE_URL` in the environment override it.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a browser logged in to the website
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    /// Event year, 2024 unless set
+    pub year: Option<u32>,
+    /// How to reach you, sent along in the User-Agent of every request
+    pub contact: Option<String>,
+    /// Id of the private leaderboard shown by `aoc leaderboard`
+    pub leaderboard: Option<String>,
+}
+
+/// Value of the environment variable `name`, ignoring unset and blank variables.
+fn env_var(name: &str) -> Option<String> {
+    env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+impl Config {
+    /// Load the config from `path`. A missing file means nothing is configured.
+    pub fn load(path: &str) -> AocResult<Config> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(err) => return Err(AocError::io("read", path, err)),
+        };
+        toml::from_str(&text).map_err(|err| AocError::from_toml(&text, &err).with_file(path))
+    }
+
+    /// Config of `remote`: the config file, overridden by the environment and then by the
+    /// command line.
+    pub fn resolve(remote: &RemoteArgs) -> AocResult<Config> {
+        let mut config = Config::load(&remote.config)?;
+        if let Some(session) = env_var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env_var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        if let Some(base_url) = &remote.base_url {
+            config.base_url = Some(base_url.clone());
+        }
+        Ok(config)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::client::Client;
+use crate::html;
+use shared::*;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::exit;
+
+/// Start of the first line of every generated description, so hand written files are left
+/// alone.
+const MARKER: &str = "<!-- Puzzle description from ";
+
+/// Number of parts described by generated `markdown`, each of which starts with a heading
+/// like `## --- Part Two ---`.
+fn parts_in(markdown: &str) -> usize {
+    markdown
+        .lines()
+        .filter(|line| line.starts_with("## ---"))
+        .count()
+}
+
+fn save_description(args: &DescribeArgs) -> AocResult<()> {
+    let day_dir = Path::new(&args.root).join(format!("day{}", args.day));
+    if !day_dir.is_dir() {
+        return Err(AocError::new(format!(
+            "{} does not exist, create it with `aoc new {}` first",
+            day_dir.display(),
+            args.day
+        )));
+    }
+    let path = day_dir.join("README.md");
+    let name = path.display().to_string();
+    let existing = match fs::read_to_string(&path) {
+        Ok(text) => Some(text),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(AocError::io("read", &name, err)),
+    };
+    if existing
+        .as_ref()
+        .is_some_and(|text| !text.starts_with(MARKER))
+    {
+        return Err(AocError::new(format!(
+            "{} was not written by `aoc describe`, refusing to overwrite it",
+            name
+        )));
+    }
+
+    let client = Client::from_args(&args.remote)?;
+    let url = client.url(&format!("day/{}", args.day));
+    let page = client.puzzle_page(args.day)?;
+    let tokens = html::tokenize(&page);
+    let articles = html::puzzle_articles(&tokens);
+    if articles.is_empty() {
+        return Err(AocError::new(format!("{} has no puzzle description", url)));
+    }
+    let mut markdown = format!("{}{}, updated by `aoc describe` -->\n", MARKER, url);
+    for article in &articles {
+        markdown.push('\n');
+        markdown.push_str(&html::to_markdown(article, client.base_url()));
+    }
+
+    if let Some(existing) = existing {
+        // without a session the page only has part 1, which must not replace part 2
+        if parts_in(&existing) > articles.len() {
+            println!(
+                "{} describes more parts than the page has, keeping it (is the session set?)",
+                name
+            );
+            return Ok(());
+        }
+        if existing == markdown {
+            println!("{} is up to date", name);
+            return Ok(());
+        }
+    }
+    fs::write(&path, &markdown).map_err(|err| AocError::io("write", &name, err))?;
+    match articles.len() {
+        1 => println!(
+            "Saved part 1 of day {} to {}, run again to add part 2 once it is unlocked",
+            args.day, name
+        ),
+        _ => println!("Saved both parts of day {} to {}", args.day, name),
+    }
+    Ok(())
+}
+
+/// Download the puzzle description of a day and save it as Markdown next to the solution.
+pub fn describe(args: &DescribeArgs) {
+    if let Err(err) = save_description(args) {
+        eprintln!("{}", err.render(None));
+        exit(1);
+    }
+}
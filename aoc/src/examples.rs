@@ -0,0 +1,228 @@
+use crate::client::Client;
+use crate::html::{self, Token};
+use shared::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::exit;
+
+/// An example input of a puzzle page.
+struct Example {
+    /// Part whose description shows the example
+    part: Part,
+    text: String,
+}
+
+/// Example inputs and candidate answers found on a puzzle page.
+struct PuzzlePage {
+    examples: Vec<Example>,
+    /// Emphasized code of the description of each part, in document order. Puzzles give the
+    /// answer of the example this way, usually as the last one.
+    answers: Vec<(Part, Vec<String>)>,
+}
+
+/// Text of the emphasized inline code of `article`, like `<code><em>11</em></code>`.
+fn emphasized_code(article: &[Token]) -> Vec<String> {
+    let mut found = Vec::new();
+    let (mut pre, mut code, mut em) = (0, 0, 0);
+    let mut current = String::new();
+    for token in article {
+        match token {
+            token if token.is_start("pre") => pre += 1,
+            token if token.is_end("pre") => pre -= 1,
+            token if token.is_start("code") => code += 1,
+            token if token.is_end("code") => code -= 1,
+            token if token.is_start("em") => em += 1,
+            token if token.is_end("em") => em -= 1,
+            Token::Text(text) if pre == 0 && code > 0 && em > 0 => current.push_str(text),
+            _ => {}
+        }
+        if (code == 0 || em == 0) && !current.is_empty() {
+            found.push(current.trim().to_string());
+            current.clear();
+        }
+    }
+    found
+}
+
+impl PuzzlePage {
+    fn parse(page: &str) -> PuzzlePage {
+        let tokens = html::tokenize(page);
+        let mut examples = Vec::new();
+        let mut answers = Vec::new();
+        for (article, part) in html::puzzle_articles(&tokens).into_iter().zip(Part::ALL) {
+            for block in html::elements(article, "pre", |_| true) {
+                examples.push(Example {
+                    part,
+                    text: html::raw_text(block),
+                });
+            }
+            answers.push((part, emphasized_code(article)));
+        }
+        PuzzlePage { examples, answers }
+    }
+
+    /// Likely answer of the example of `part`.
+    fn guess_answer(&self, part: Part) -> Option<Answer> {
+        let (_, answers) = self.answers.iter().find(|(p, _)| *p == part)?;
+        answers.last().map(|answer| answer.parse().unwrap())
+    }
+}
+
+/// Print the numbered examples of `page` with a preview of each, and the candidate answers.
+fn list(day: u32, page: &PuzzlePage) {
+    const PREVIEW_LINES: usize = 4;
+    println!("Examples of day {}:", day);
+    for (i, example) in page.examples.iter().enumerate() {
+        let lines: Vec<&str> = example.text.lines().collect();
+        println!("  [{}] part {}, {} lines", i + 1, example.part, lines.len());
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("      {}", line);
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("      ...");
+        }
+    }
+    println!("Emphasized code, the last one is guessed to be the answer:");
+    for (part, answers) in &page.answers {
+        println!("  part {}: {}", part, answers.join(", "));
+    }
+    println!("Store an example with `aoc examples {} --pick N`", day);
+}
+
+/// Add the table of `answers` for the example `name` to the answers file `text`, after the
+/// last table of the same day or else at the end.
+fn insert_answers(text: &str, day: u32, name: &str, answers: &BTreeMap<String, Answer>) -> String {
+    let table = format!(
+        "[day{}.{}]\n{}",
+        day,
+        name,
+        toml::to_string(answers).unwrap()
+    );
+    let mut lines: Vec<&str> = text.lines().collect();
+    let day_prefix = format!("[day{}.", day);
+    let Some(start) = lines.iter().rposition(|line| line.starts_with(&day_prefix)) else {
+        return match text.trim_end() {
+            "" => table,
+            text => format!("{}\n\n{}", text, table),
+        };
+    };
+    let mut end = lines[start + 1..]
+        .iter()
+        .position(|line| line.starts_with('['))
+        .map_or(lines.len(), |i| start + 1 + i);
+    while lines[end - 1].trim().is_empty() {
+        end -= 1;
+    }
+    lines.splice(end..end, std::iter::once("").chain(table.lines()));
+    lines.join("\n") + "\n"
+}
+
+fn store(args: &ExamplesArgs, page: &PuzzlePage, pick: usize) -> AocResult<()> {
+    let example = pick
+        .checked_sub(1)
+        .and_then(|i| page.examples.get(i))
+        .ok_or_else(|| {
+            AocError::new(format!(
+                "day {} has {} examples, cannot pick example {}",
+                args.day,
+                page.examples.len(),
+                pick
+            ))
+        })?;
+    let path = Path::new(&args.examples)
+        .join(format!("day{}", args.day))
+        .join(format!("{}.txt", args.name));
+    let name = path.display().to_string();
+    let expected = ExpectedAnswers::load(&args.answers)?;
+    // `aoc new` leaves an empty example behind to be filled in
+    let placeholder = fs::metadata(&path).is_ok_and(|metadata| metadata.len() == 0);
+    if (path.exists() && !placeholder)
+        || Part::ALL
+            .iter()
+            .any(|&part| expected.get(args.day, &args.name, part).is_some())
+    {
+        return Err(AocError::new(format!(
+            "example {} of day {} exists already, pick another --name",
+            args.name, args.day
+        )));
+    }
+
+    // an example shown for part 2 says nothing about the answer of part 1
+    let mut answers = BTreeMap::new();
+    let part1 = match example.part {
+        Part::One => args.part1.clone().or_else(|| page.guess_answer(Part::One)),
+        Part::Two => args.part1.clone(),
+    };
+    let part2 = args.part2.clone().or_else(|| page.guess_answer(Part::Two));
+    for (part, answer) in Part::ALL.into_iter().zip([part1, part2]) {
+        if let Some(answer) = answer {
+            answers.insert(format!("part{}", part), answer);
+        }
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| AocError::io("create directory", &dir.display().to_string(), err))?;
+    }
+    let mut text = example.text.clone();
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    fs::write(&path, text).map_err(|err| AocError::io("write", &name, err))?;
+    println!("Saved example {} to {}", pick, name);
+
+    if answers.is_empty() {
+        println!("No answers found, add them to {} by hand", args.answers);
+        return Ok(());
+    }
+    let current = match fs::read_to_string(&args.answers) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(AocError::io("read", &args.answers, err)),
+    };
+    let updated = insert_answers(&current, args.day, &args.name, &answers);
+    fs::write(&args.answers, updated).map_err(|err| AocError::io("write", &args.answers, err))?;
+    let summary: Vec<String> = answers
+        .iter()
+        .map(|(part, answer)| format!("{} = {}", part, answer))
+        .collect();
+    println!(
+        "Added day{}.{} to {}: {}",
+        args.day,
+        args.name,
+        args.answers,
+        summary.join(", ")
+    );
+    Ok(())
+}
+
+fn extract(args: &ExamplesArgs) -> AocResult<()> {
+    let page = match &args.page {
+        Some(path) => fs::read_to_string(path).map_err(|err| AocError::io("read", path, err))?,
+        None => Client::from_args(&args.remote)?.puzzle_page(args.day)?,
+    };
+    let page = PuzzlePage::parse(&page);
+    if page.examples.is_empty() {
+        return Err(AocError::new(format!(
+            "no examples found on the page of day {}",
+            args.day
+        )));
+    }
+    match args.pick {
+        Some(pick) => store(args, &page, pick),
+        None => {
+            list(args.day, &page);
+            Ok(())
+        }
+    }
+}
+
+/// List the example inputs of a puzzle page, or store the picked one as a fixture of the
+/// example tests together with its expected answers.
+pub fn examples(args: &ExamplesArgs) {
+    if let Err(err) = extract(args) {
+        eprintln!("{}", err.render(None));
+        exit(1);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::client::Client;
+use shared::*;
+use std::fs;
+use std::process::exit;
+
+fn download(args: &FetchArgs) -> AocResult<()> {
+    let path = default_input_path(&args.inputs, args.day);
+    // inputs never change, so an existing file is never downloaded again
+    if path.exists() {
+        println!(
+            "{} already exists, not downloading it again",
+            path.display()
+        );
+        return Ok(());
+    }
+    let client = Client::from_args(&args.remote)?;
+    let input = client.puzzle_input(args.day)?;
+
+    fs::create_dir_all(&args.inputs)
+        .map_err(|err| AocError::io("create directory", &args.inputs, err))?;
+    // write to a temporary file first so an interrupted write does not leave a cached
+    // partial input behind
+    let partial = path.with_extension("txt.part");
+    let partial_name = partial.display().to_string();
+    fs::write(&partial, &input).map_err(|err| AocError::io("write", &partial_name, err))?;
+    fs::rename(&partial, &path).map_err(|err| {
+        AocError::io(&format!("rename to {}", path.display()), &partial_name, err)
+    })?;
+    println!("Saved the input of day {} to {}", args.day, path.display());
+    Ok(())
+}
+
+/// Download the puzzle input of a day into the inputs directory, unless it is there already.
+pub fn fetch(args: &FetchArgs) {
+    if let Err(err) = download(args) {
+        eprintln!("{}", err.render(None));
+        exit(1);
+    }
+}
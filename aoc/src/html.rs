@@ -0,0 +1,353 @@
+//! Just enough HTML handling for the pages of the website, which are simple and well formed.
+
+/// A piece of an HTML document. Tag and attribute names are lowercase, text and attribute
+/// values have their character references decoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    Start {
+        name: String,
+        attrs: Vec<(String, String)>,
+    },
+    End {
+        name: String,
+    },
+    Text(String),
+}
+
+impl Token {
+    pub fn is_start(&self, tag: &str) -> bool {
+        matches!(self, Token::Start { name, .. } if name == tag)
+    }
+
+    pub fn is_end(&self, tag: &str) -> bool {
+        matches!(self, Token::End { name } if name == tag)
+    }
+
+    /// Value of the attribute `attr` of a start tag.
+    pub fn attr(&self, attr: &str) -> Option<&str> {
+        match self {
+            Token::Start { attrs, .. } => attrs
+                .iter()
+                .find(|(name, _)| name == attr)
+                .map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Elements whose contents are not HTML.
+const RAW_TEXT: [&str; 2] = ["script", "style"];
+
+/// Split `html` into tokens, dropping comments, doctypes and the contents of scripts and
+/// styles. Unterminated markup at the end is treated as text.
+pub fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(open) = rest.find('<') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        if open > 0 {
+            tokens.push(Token::Text(decode_entities(&rest[..open])));
+        }
+        rest = &rest[open..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(close) = rest.find('>') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        let tag = &rest[1..close];
+        rest = &rest[close + 1..];
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::End {
+                name: name.trim().to_ascii_lowercase(),
+            });
+            continue;
+        }
+        let (name, attrs) = parse_tag(tag.trim_end_matches('/'));
+        if RAW_TEXT.contains(&name.as_str()) {
+            let end = format!("</{}", name);
+            rest = rest
+                .to_ascii_lowercase()
+                .find(&end)
+                .map_or("", |i| &rest[i..]);
+        }
+        tokens.push(Token::Start { name, attrs });
+    }
+    tokens
+}
+
+/// Name and attributes of the inside of a start tag.
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>) {
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let name = tag[..name_end].to_ascii_lowercase();
+    let mut attrs = Vec::new();
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_ascii_lowercase();
+        rest = rest[key_end..].trim_start();
+        let value = match rest.strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+                let (value, remaining) = match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let end = after[1..].find(quote).map_or(after.len(), |i| i + 1);
+                        (&after[1..end], after.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                        (&after[..end], &after[end..])
+                    }
+                };
+                rest = remaining.trim_start();
+                decode_entities(value)
+            }
+            None => String::new(),
+        };
+        attrs.push((key, value));
+    }
+    (name, attrs)
+}
+
+/// Replace character references like `&lt;` and `&#39;` with the characters they stand
+/// for. Unknown references are kept as they are.
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').and_then(|semi| {
+            let c = match &rest[1..semi] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => '\u{a0}',
+                reference => {
+                    let number = reference.strip_prefix('#')?;
+                    let code = match number.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => number.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, semi + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Text of every `tag` element of `tokens` in document order, with whitespace collapsed.
+pub fn texts_of(tokens: &[Token], tag: &str) -> Vec<String> {
+    let mut texts = Vec::new();
+    let mut depth = 0;
+    let mut text = String::new();
+    for token in tokens {
+        match token {
+            token if token.is_start(tag) => depth += 1,
+            token if token.is_end(tag) && depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    texts.push(text.split_whitespace().collect::<Vec<_>>().join(" "));
+                    text.clear();
+                }
+            }
+            Token::Text(t) if depth > 0 => text.push_str(t),
+            _ => {}
+        }
+    }
+    texts
+}
+
+/// Contents of every `tag` element of `tokens` whose start tag satisfies `select`, without
+/// the start and end tags themselves. Nested elements are part of the outer one.
+pub fn elements<'a>(
+    tokens: &'a [Token],
+    tag: &str,
+    select: impl Fn(&Token) -> bool,
+) -> Vec<&'a [Token]> {
+    let mut elements = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if !(tokens[i].is_start(tag) && select(&tokens[i])) {
+            i += 1;
+            continue;
+        }
+        let mut depth = 0;
+        let mut end = tokens.len();
+        for (j, token) in tokens.iter().enumerate().skip(i) {
+            if token.is_start(tag) {
+                depth += 1;
+            } else if token.is_end(tag) {
+                depth -= 1;
+                if depth == 0 {
+                    end = j;
+                    break;
+                }
+            }
+        }
+        elements.push(&tokens[i + 1..end]);
+        i = end + 1;
+    }
+    elements
+}
+
+/// The articles of a puzzle page, one per unlocked part.
+pub fn puzzle_articles(tokens: &[Token]) -> Vec<&[Token]> {
+    elements(tokens, "article", |token| {
+        token
+            .attr("class")
+            .is_some_and(|class| class.split_whitespace().any(|class| class == "day-desc"))
+    })
+}
+
+/// All text of `tokens` as it is, without collapsing whitespace.
+pub fn raw_text(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Markdown being written from a stream of tokens.
+#[derive(Default)]
+struct Markdown {
+    out: String,
+    /// Inline text of the current block, whitespace is collapsed when the block ends
+    text: String,
+    /// Start of the current block, like `## ` or `- `
+    prefix: String,
+    in_pre: bool,
+    /// Inline code being collected, and whether any of it is emphasized
+    code: Option<(String, bool)>,
+    /// Targets of the links being written
+    links: Vec<String>,
+}
+
+impl Markdown {
+    fn end_block(&mut self, separator: &str) {
+        let text = self.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !text.is_empty() {
+            self.out.push_str(&self.prefix);
+            self.out.push_str(&text);
+            self.out.push_str(separator);
+        }
+        self.text.clear();
+        self.prefix.clear();
+    }
+
+    fn token(&mut self, token: &Token, base_url: &str) {
+        match token {
+            Token::Text(text) => match &mut self.code {
+                Some((code, _)) if !self.in_pre => code.push_str(text),
+                _ => self.text.push_str(text),
+            },
+            Token::Start { name, .. } => match name.as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    self.end_block("\n\n");
+                    let level = name[1..].parse().unwrap_or(2);
+                    self.prefix = "#".repeat(level) + " ";
+                }
+                "p" | "ul" | "ol" => self.end_block("\n\n"),
+                "li" => {
+                    self.end_block("\n");
+                    self.prefix = "- ".to_string();
+                }
+                "pre" => {
+                    self.end_block("\n\n");
+                    self.in_pre = true;
+                }
+                "code" if !self.in_pre => self.code = Some((String::new(), false)),
+                "em" => match &mut self.code {
+                    Some((_, emphasized)) => *emphasized = true,
+                    None if !self.in_pre => self.text.push('*'),
+                    None => {}
+                },
+                "a" => {
+                    let href = token.attr("href").unwrap_or_default();
+                    let href = if href.starts_with('/') {
+                        format!("{}{}", base_url, href)
+                    } else {
+                        href.to_string()
+                    };
+                    self.links.push(href);
+                    self.text.push('[');
+                }
+                _ => {}
+            },
+            Token::End { name } => match name.as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "p" => self.end_block("\n\n"),
+                "li" => self.end_block("\n"),
+                "ul" | "ol" => {
+                    self.end_block("\n");
+                    self.out.push('\n');
+                }
+                "pre" => {
+                    self.out.push_str("```\n");
+                    self.out.push_str(&self.text);
+                    if !self.text.ends_with('\n') {
+                        self.out.push('\n');
+                    }
+                    self.out.push_str("```\n\n");
+                    self.text.clear();
+                    self.in_pre = false;
+                }
+                "code" if !self.in_pre => {
+                    if let Some((code, emphasized)) = self.code.take() {
+                        let code = format!("`{}`", code);
+                        if emphasized {
+                            self.text.push_str(&format!("**{}**", code));
+                        } else {
+                            self.text.push_str(&code);
+                        }
+                    }
+                }
+                "em" if self.code.is_none() && !self.in_pre => self.text.push('*'),
+                "a" => {
+                    let href = self.links.pop().unwrap_or_default();
+                    self.text.push_str(&format!("]({})", href));
+                }
+                _ => {}
+            },
+        }
+    }
+}
+
+/// Render `tokens` as Markdown: headings, paragraphs, lists, code blocks, inline code,
+/// emphasis and links. Links relative to the website are made absolute with `base_url`.
+/// Other markup is dropped, keeping its text.
+pub fn to_markdown(tokens: &[Token], base_url: &str) -> String {
+    let mut markdown = Markdown::default();
+    for token in tokens {
+        markdown.token(token, base_url);
+    }
+    markdown.end_block("\n\n");
+    markdown.out.trim_end().to_string() + "\n"
+}
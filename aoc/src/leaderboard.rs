@@ -0,0 +1,287 @@
+use crate::client::Client;
+use crate::config::Config;
+use crate::report::print_boxed;
+use serde::{Deserialize, Serialize};
+use shared::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::exit;
+use std::time::Duration;
+
+/// The website asks not to download a leaderboard more often than this.
+const CACHE_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard as served by the website.
+#[derive(Debug, Deserialize)]
+struct Leaderboard {
+    members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Member {
+    id: u64,
+    /// Unset for anonymous users
+    name: Option<String>,
+    stars: u32,
+    local_score: u64,
+    /// Seconds since the Unix epoch, 0 without any stars
+    #[serde(default)]
+    last_star_ts: u64,
+    /// Stars keyed by day and then part
+    #[serde(default)]
+    completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Star {
+    get_star_ts: u64,
+}
+
+/// A member of the leaderboard with their place in it. Times are seconds since the Unix epoch.
+#[derive(Debug, Serialize)]
+struct Standing {
+    rank: usize,
+    id: u64,
+    name: String,
+    stars: u32,
+    local_score: u64,
+    last_star_ts: Option<u64>,
+    days: Vec<DayCompletion>,
+}
+
+/// When a member got the stars of one day.
+#[derive(Debug, Serialize)]
+struct DayCompletion {
+    day: u32,
+    part1_ts: Option<u64>,
+    part2_ts: Option<u64>,
+    /// Seconds from the star of part 1 to the star of part 2
+    delta_secs: Option<u64>,
+}
+
+impl Member {
+    fn days(&self) -> Vec<DayCompletion> {
+        let mut days: Vec<DayCompletion> = self
+            .completion_day_level
+            .iter()
+            .filter_map(|(day, parts)| {
+                let star = |part: &str| parts.get(part).map(|star| star.get_star_ts);
+                let (part1_ts, part2_ts) = (star("1"), star("2"));
+                Some(DayCompletion {
+                    day: day.parse().ok()?,
+                    part1_ts,
+                    part2_ts,
+                    delta_secs: part1_ts
+                        .zip(part2_ts)
+                        .map(|(one, two)| two.saturating_sub(one)),
+                })
+            })
+            .collect();
+        days.sort_by_key(|completion| completion.day);
+        days
+    }
+}
+
+/// Members ordered like on the website: by local score, then by who got their last star
+/// first.
+fn standings(leaderboard: Leaderboard) -> Vec<Standing> {
+    let mut members: Vec<Member> = leaderboard.members.into_values().collect();
+    members.sort_by_key(|member| {
+        (
+            std::cmp::Reverse(member.local_score),
+            std::cmp::Reverse(member.stars),
+            member.last_star_ts,
+            member.id,
+        )
+    });
+    members
+        .into_iter()
+        .enumerate()
+        .map(|(i, member)| Standing {
+            rank: i + 1,
+            id: member.id,
+            name: member
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+            stars: member.stars,
+            local_score: member.local_score,
+            last_star_ts: (member.last_star_ts > 0).then_some(member.last_star_ts),
+            days: member.days(),
+        })
+        .collect()
+}
+
+/// `2024-12-01 05:03:11`, in UTC.
+fn format_timestamp(ts: u64) -> String {
+    let (days, secs) = (ts / 86400, ts % 86400);
+    // days since 1970-01-01 to a date, http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// `45s`, `7m 31s`, `2h 05m 09s` or `3d 04h 12m`.
+fn format_delta(secs: u64) -> String {
+    let (d, h, m, s) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    match secs {
+        0..60 => format!("{}s", s),
+        60..3600 => format!("{}m {:02}s", m, s),
+        3600..86400 => format!("{}h {:02}m {:02}s", h, m, s),
+        _ => format!("{}d {:02}h {:02}m", d, h, m),
+    }
+}
+
+fn print_tables(standings: &[Standing]) {
+    let optional = |ts: Option<u64>| ts.map(format_timestamp).unwrap_or_default();
+    let rows: Vec<Vec<String>> = standings
+        .iter()
+        .map(|standing| {
+            vec![
+                standing.rank.to_string(),
+                standing.name.clone(),
+                standing.stars.to_string(),
+                standing.local_score.to_string(),
+                optional(standing.last_star_ts),
+            ]
+        })
+        .collect();
+    print_boxed(
+        &["Rank", "Name", "Stars", "Score", "Last star (UTC)"],
+        &rows,
+        &[1],
+    );
+
+    let mut rows: Vec<(u32, Vec<String>)> = standings
+        .iter()
+        .flat_map(|standing| {
+            standing.days.iter().map(|completion| {
+                let row = vec![
+                    completion.day.to_string(),
+                    standing.name.clone(),
+                    optional(completion.part1_ts),
+                    optional(completion.part2_ts),
+                    completion.delta_secs.map(format_delta).unwrap_or_default(),
+                ];
+                (completion.day, row)
+            })
+        })
+        .collect();
+    if rows.is_empty() {
+        return;
+    }
+    // members keep their rank order within each day
+    rows.sort_by_key(|(day, _)| *day);
+    let rows: Vec<Vec<String>> = rows.into_iter().map(|(_, row)| row).collect();
+    println!();
+    print_boxed(
+        &["Day", "Name", "Part 1 (UTC)", "Part 2 (UTC)", "Part 1 -> 2"],
+        &rows,
+        &[1],
+    );
+}
+
+fn print(standings: &[Standing], format: OutputFormat) {
+    match format {
+        OutputFormat::Text => print_tables(standings),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(standings).unwrap()),
+        OutputFormat::Ndjson => {
+            for standing in standings {
+                println!("{}", serde_json::to_string(standing).unwrap());
+            }
+        }
+    }
+}
+
+fn is_fresh(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .is_ok_and(|modified| {
+            modified
+                .elapsed()
+                .is_ok_and(|elapsed| elapsed < CACHE_MAX_AGE)
+        })
+}
+
+/// JSON of the leaderboard of `args`, from the file, the cache if it is recent enough, or
+/// else downloaded into the cache. A stale cache is used when downloading fails.
+fn load_json(args: &LeaderboardArgs) -> AocResult<(String, String)> {
+    if let Some(file) = &args.file {
+        let json = fs::read_to_string(file).map_err(|err| AocError::io("read", file, err))?;
+        return Ok((file.clone(), json));
+    }
+    let config = Config::resolve(&args.remote)?;
+    let id = args
+        .id
+        .clone()
+        .or(config.leaderboard.clone())
+        .ok_or_else(|| {
+            AocError::new("no leaderboard, use --id or set `leaderboard` in the config file")
+        })?;
+    let client = Client::new(&config);
+    let path = Path::new(&args.cache).join(format!("leaderboard-{}-{}.json", client.year(), id));
+    let name = path.display().to_string();
+    if is_fresh(&path) {
+        let json = fs::read_to_string(&path).map_err(|err| AocError::io("read", &name, err))?;
+        return Ok((name, json));
+    }
+    match client.leaderboard(&id) {
+        Ok(json) => {
+            // only cache what is a leaderboard, not a login page
+            if serde_json::from_str::<Leaderboard>(&json).is_ok() {
+                fs::create_dir_all(&args.cache)
+                    .map_err(|err| AocError::io("create directory", &args.cache, err))?;
+                fs::write(&path, &json).map_err(|err| AocError::io("write", &name, err))?;
+            }
+            Ok((
+                client.url(&format!("leaderboard/private/view/{}.json", id)),
+                json,
+            ))
+        }
+        Err(err) => match fs::read_to_string(&path) {
+            Ok(json) => {
+                eprintln!("warning: {}, showing the cached {}", err, name);
+                Ok((name, json))
+            }
+            Err(_) => Err(err),
+        },
+    }
+}
+
+fn show(args: &LeaderboardArgs) -> AocResult<()> {
+    let (source, json) = load_json(args)?;
+    let leaderboard: Leaderboard = serde_json::from_str(&json).map_err(|err| {
+        AocError::new(format!(
+            "not a leaderboard (is the session token valid?): {}",
+            err
+        ))
+        .with_file(source)
+    })?;
+    print(&standings(leaderboard), args.format);
+    Ok(())
+}
+
+/// Print the standings of a private leaderboard: stars and local score of every member,
+/// and when they solved each part of each day.
+pub fn leaderboard(args: &LeaderboardArgs) {
+    if let Err(err) = show(args) {
+        eprintln!("{}", err.render(None));
+        exit(1);
+    }
+}
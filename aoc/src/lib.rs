@@ -0,0 +1,15 @@
+use shared::Registry;
+
+/// Registry with the solutions of every day in the workspace.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register::<day1::Day1>();
+    registry.register::<day2::Day2>();
+    registry.register::<day3::Day3>();
+    registry.register::<day4::Day4>();
+    registry.register::<day5::Day5>();
+    registry.register::<day6::Day6>();
+    registry.register::<day7::Day7>();
+    registry.register::<day8::Day8>();
+    registry
+}
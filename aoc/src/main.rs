@@ -0,0 +1,31 @@
+use shared::*;
+
+mod bench;
+mod client;
+mod config;
+mod describe;
+mod examples;
+mod fetch;
+mod html;
+mod leaderboard;
+mod new;
+mod report;
+mod run;
+mod submit;
+
+fn main() {
+    let cli = Cli::argparse();
+    set_debug(cli.debug);
+    let registry = aoc::registry();
+
+    match cli.command {
+        Command::Run(args) => run::run(&registry, &args),
+        Command::Bench(args) => bench::bench(&registry, &args),
+        Command::New(args) => new::new(&args),
+        Command::Fetch(args) => fetch::fetch(&args),
+        Command::Submit(args) => submit::submit(&registry, &args),
+        Command::Describe(args) => describe::describe(&args),
+        Command::Examples(args) => examples::examples(&args),
+        Command::Leaderboard(args) => leaderboard::leaderboard(&args),
+    }
+}
@@ -0,0 +1,181 @@
+use shared::*;
+use std::fs;
+use std::path::Path;
+use std::process::exit;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+
+fn render(template: &str, day: u32) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+fn read(path: &Path) -> AocResult<String> {
+    fs::read_to_string(path).map_err(|err| AocError::io("read", &path.display().to_string(), err))
+}
+
+fn write(path: &Path, contents: &str) -> AocResult<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| AocError::io("create directory", &dir.display().to_string(), err))?;
+    }
+    fs::write(path, contents).map_err(|err| AocError::io("write", &path.display().to_string(), err))
+}
+
+/// Add the new day as a dependency of the runner, after the last day already listed.
+fn register_dependency(manifest: &str, day: u32) -> AocResult<String> {
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let name = format!("day{}", day);
+    if lines
+        .iter()
+        .any(|line| line.split('=').next().map(str::trim) == Some(name.as_str()))
+    {
+        return Err(AocError::new(format!(
+            "{} is already a dependency of the runner",
+            name
+        )));
+    }
+    let last_day = lines
+        .iter()
+        .rposition(|line| line.starts_with("day"))
+        .ok_or_else(|| AocError::new("no day dependencies found in the runner manifest"))?;
+    let dependency = format!("day{day} = {{ path = \"../day{day}\" }}");
+    lines.insert(last_day + 1, &dependency);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Register the new day in `aoc::registry`, after the last registered day.
+fn register_solution(lib: &str, day: u32) -> AocResult<String> {
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let prefix = format!("registry.register::<day{}::", day);
+    if lines
+        .iter()
+        .any(|line| line.trim_start().starts_with(&prefix))
+    {
+        return Err(AocError::new(format!(
+            "day {} is already registered with the runner",
+            day
+        )));
+    }
+    let last_day = lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with("registry.register::<"))
+        .ok_or_else(|| AocError::new("no registered days found in the runner"))?;
+    let registration = format!("    registry.register::<day{day}::Day{day}>();");
+    lines.insert(last_day + 1, &registration);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn scaffold(args: &NewArgs) -> AocResult<()> {
+    let day = args.day;
+    let root = Path::new(&args.root);
+    let crate_dir = root.join(format!("day{}", day));
+    let examples_dir = root.join("examples").join(format!("day{}", day));
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let runner_lib = root.join("aoc").join("src").join("lib.rs");
+
+    if !root.join("shared").is_dir() || !runner_lib.is_file() {
+        return Err(AocError::new(format!(
+            "{} is not the root of the workspace",
+            root.display()
+        )));
+    }
+    // refuse to touch anything if the day exists in any form
+    for existing in [&crate_dir, &examples_dir] {
+        if existing.exists() {
+            return Err(AocError::new(format!(
+                "{} already exists, refusing to overwrite it",
+                existing.display()
+            )));
+        }
+    }
+    let manifest = register_dependency(&read(&runner_manifest)?, day)?;
+    let lib = register_solution(&read(&runner_lib)?, day)?;
+
+    write(&crate_dir.join("Cargo.toml"), &render(CARGO_TEMPLATE, day))?;
+    write(
+        &crate_dir.join("src").join("lib.rs"),
+        &render(LIB_TEMPLATE, day),
+    )?;
+    write(
+        &crate_dir.join("src").join("main.rs"),
+        &render(MAIN_TEMPLATE, day),
+    )?;
+    write(&examples_dir.join("example.txt"), "")?;
+    write(&runner_manifest, &manifest)?;
+    write(&runner_lib, &lib)?;
+
+    println!("Created {}", crate_dir.display());
+    println!("Created {}", examples_dir.join("example.txt").display());
+    println!("Registered day {} in {}", day, runner_lib.display());
+    Ok(())
+}
+
+/// Create the crate of a new day from the templates and register it with the runner.
+pub fn new(args: &NewArgs) {
+    if let Err(err) = scaffold(args) {
+        eprintln!("{}", err.render(None));
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[package]
+name = \"aoc\"
+
+[dependencies]
+shared = { path = \"../shared\" }
+day1 = { path = \"../day1\" }
+day2 = { path = \"../day2\" }
+serde = \"1.0\"
+";
+
+    const LIB: &str = "use shared::Registry;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register::<day1::Day1>();
+    registry.register::<day2::Day2>();
+    registry
+}
+";
+
+    #[test]
+    fn dependency_goes_after_the_last_day() {
+        assert_eq!(
+            register_dependency(MANIFEST, 10).unwrap(),
+            MANIFEST.replace(
+                "day2 = { path = \"../day2\" }\n",
+                "day2 = { path = \"../day2\" }\nday10 = { path = \"../day10\" }\n"
+            )
+        );
+        let err = register_dependency(MANIFEST, 2).unwrap_err();
+        assert_eq!(err.message, "day2 is already a dependency of the runner");
+        let err = register_dependency("[dependencies]\nshared = \"1\"\n", 1).unwrap_err();
+        assert_eq!(
+            err.message,
+            "no day dependencies found in the runner manifest"
+        );
+    }
+
+    #[test]
+    fn solution_goes_after_the_last_registration() {
+        assert_eq!(
+            register_solution(LIB, 3).unwrap(),
+            LIB.replace(
+                "Day2>();\n",
+                "Day2>();\n    registry.register::<day3::Day3>();\n"
+            )
+        );
+        // day1 must not count as day 10 or the other way around
+        assert!(register_solution(LIB, 10).is_ok());
+        let err = register_solution(LIB, 1).unwrap_err();
+        assert_eq!(err.message, "day 1 is already registered with the runner");
+        let err = register_solution("pub fn registry() {}\n", 1).unwrap_err();
+        assert_eq!(err.message, "no registered days found in the runner");
+    }
+}
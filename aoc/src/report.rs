@@ -0,0 +1,161 @@
+use serde::Serialize;
+use shared::*;
+use std::time::Duration;
+
+/// Outcome of one part of a day: the answer, or why there is none.
+pub struct Row {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Result<Answer, String>,
+    /// Time spent parsing the input of the day, shared by both parts
+    pub parse_time: Option<Duration>,
+    pub solve_time: Option<Duration>,
+    /// Set when running with `--check`
+    pub check: Option<CheckStatus>,
+}
+
+/// Machine readable form of a `Row`.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u32,
+    part: u8,
+    answer: Option<&'a Answer>,
+    error: Option<&'a str>,
+    parse_ms: Option<f64>,
+    solve_ms: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<&'a Answer>,
+}
+
+impl Row {
+    fn record(&self) -> Record<'_> {
+        Record {
+            day: self.day,
+            part: self.part.number(),
+            answer: self.answer.as_ref().ok(),
+            error: self.answer.as_ref().err().map(|err| err.as_str()),
+            parse_ms: self.parse_time.map(millis),
+            solve_ms: self.solve_time.map(millis),
+            check: self.check.as_ref().map(CheckStatus::label),
+            expected: match &self.check {
+                Some(CheckStatus::Fail { expected }) => Some(expected),
+                _ => None,
+            },
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn print(rows: &[Row], format: OutputFormat) {
+    match format {
+        OutputFormat::Text => print_table(rows),
+        OutputFormat::Json => {
+            let records: Vec<Record> = rows.iter().map(Row::record).collect();
+            println!("{}", serde_json::to_string_pretty(&records).unwrap());
+        }
+        OutputFormat::Ndjson => {
+            for row in rows {
+                println!("{}", serde_json::to_string(&row.record()).unwrap());
+            }
+        }
+    }
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    time.map(|time| format!("{:.3} ms", millis(time)))
+        .unwrap_or_default()
+}
+
+fn print_table(rows: &[Row]) {
+    if rows.is_empty() {
+        return;
+    }
+    let with_check = rows.iter().any(|row| row.check.is_some());
+    let mut header = vec!["Day", "Part", "Answer", "Parse", "Solve"];
+    if with_check {
+        header.push("Check");
+    }
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let answer = match &row.answer {
+                Ok(answer) => answer.to_string(),
+                Err(err) => format!("<{}>", err),
+            };
+            // the parse time is shared by the parts, so only show it once per day
+            let first_of_day = i == 0 || rows[i - 1].day != row.day;
+            let parse_time = if first_of_day {
+                format_time(row.parse_time)
+            } else {
+                String::new()
+            };
+            let mut cells = vec![
+                row.day.to_string(),
+                row.part.to_string(),
+                answer,
+                parse_time,
+                format_time(row.solve_time),
+            ];
+            if with_check {
+                cells.push(match &row.check {
+                    Some(CheckStatus::Fail { expected }) => format!("FAIL, expected {}", expected),
+                    Some(status) => status.label().to_string(),
+                    None => String::new(),
+                });
+            }
+            cells
+        })
+        .collect();
+    // answers are left aligned, numbers and times right aligned
+    print_boxed(&header, &cells, &[2, 5]);
+}
+
+/// Print `rows` as a table with borders below `header`. Cells of the columns in
+/// `left_aligned` are aligned to the left, all others to the right.
+pub fn print_boxed(header: &[&str], rows: &[Vec<String>], left_aligned: &[usize]) {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .chain([header[col].chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let separator = widths
+        .iter()
+        .map(|&width| "-".repeat(width + 2))
+        .collect::<Vec<_>>()
+        .join("+");
+    let separator = format!("+{}+", separator);
+    let format_row = |row: &[&str]| {
+        let cols = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(col, (cell, &width))| {
+                if left_aligned.contains(&col) {
+                    format!(" {:<width$} ", cell)
+                } else {
+                    format!(" {:>width$} ", cell)
+                }
+            })
+            .collect::<Vec<_>>();
+        format!("|{}|", cols.join("|"))
+    };
+
+    println!("{}", separator);
+    println!("{}", format_row(header));
+    println!("{}", separator);
+    for row in rows {
+        let row: Vec<&str> = row.iter().map(|cell| cell.as_str()).collect();
+        println!("{}", format_row(&row));
+    }
+    println!("{}", separator);
+}
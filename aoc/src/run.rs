@@ -0,0 +1,123 @@
+use crate::report::{self, Row};
+use shared::*;
+use std::process::exit;
+
+/// Run the days and parts selected by `args` and print their answers in the requested format.
+pub fn run(registry: &Registry, args: &RunArgs) {
+    let days: Vec<u32> = match args.day {
+        Some(day) => vec![day],
+        None => registry.days().collect(),
+    };
+    let parts: Vec<Part> = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let expected = if args.check {
+        match ExpectedAnswers::load(&args.answers) {
+            Ok(expected) => Some(expected),
+            Err(err) => {
+                eprintln!("{}", err.render(None));
+                exit(1);
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut rows = Vec::new();
+    let mut failed = false;
+    for day in days {
+        let Some(solution) = registry.get(day) else {
+            eprintln!("error: day {} is not implemented", day);
+            exit(1);
+        };
+        let input = match args.input.read(day) {
+            Ok(input) => input,
+            // a single missing input is not fatal when running everything
+            Err(err) if args.all => {
+                for &part in &parts {
+                    let answer = Err(format!("no input: {}", err));
+                    rows.push(Row {
+                        day,
+                        part,
+                        answer,
+                        parse_time: None,
+                        solve_time: None,
+                        check: None,
+                    });
+                }
+                continue;
+            }
+            Err(err) => {
+                eprintln!("{}", err.render(None));
+                exit(1);
+            }
+        };
+        match solution.run(&input.text, &parts) {
+            Ok(day_run) => {
+                for answer in day_run.answers {
+                    let check = expected.as_ref().map(|expected| {
+                        let key = input.key.as_deref();
+                        expected.check(day, key, answer.part, &answer.answer)
+                    });
+                    rows.push(Row {
+                        day,
+                        part: answer.part,
+                        answer: Ok(answer.answer),
+                        parse_time: Some(day_run.parse_elapsed),
+                        solve_time: Some(answer.elapsed),
+                        check,
+                    });
+                }
+            }
+            Err(err) => {
+                let err = err.with_file(input.name);
+                eprintln!("{}", err.render(Some(&input.text)));
+                failed = true;
+                for &part in &parts {
+                    rows.push(Row {
+                        day,
+                        part,
+                        answer: Err(err.to_string()),
+                        parse_time: None,
+                        solve_time: None,
+                        check: None,
+                    });
+                }
+            }
+        }
+    }
+
+    if args.all || !failed {
+        report::print(&rows, args.format);
+    }
+    // with --check, parts that could not be checked at all fail too
+    let check_failed = args.check && {
+        print_check_summary(&rows);
+        rows.iter()
+            .any(|row| row.answer.is_err() || matches!(row.check, Some(CheckStatus::Fail { .. })))
+    };
+    if failed || check_failed {
+        exit(1);
+    }
+}
+
+/// Count the check results of `rows` on stderr, so it does not mix with machine readable
+/// output.
+fn print_check_summary(rows: &[Row]) {
+    let count = |label: &str| {
+        rows.iter()
+            .filter(|row| row.check.as_ref().map(CheckStatus::label) == Some(label))
+            .count()
+    };
+    let errors = rows.iter().filter(|row| row.answer.is_err()).count();
+    eprintln!(
+        "Checked {} answers: {} passed, {} failed, {} unknown, {} without an answer",
+        rows.len(),
+        count("pass"),
+        count("fail"),
+        count("unknown"),
+        errors
+    );
+}
@@ -0,0 +1,268 @@
+use crate::client::Client;
+use crate::html;
+use serde::{Deserialize, Serialize};
+use shared::*;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::process::exit;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the website said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction
+    Wrong,
+    /// Submitted too soon after the previous answer, nothing was checked
+    RateLimited {
+        wait_secs: u64,
+    },
+    /// The part was solved before, nothing was checked
+    AlreadySolved,
+    /// A response page which is not understood, with its text
+    Unknown {
+        message: String,
+    },
+}
+
+impl Outcome {
+    /// Outcome described by the response page of a submission.
+    pub fn parse(page: &str) -> Outcome {
+        let tokens = html::tokenize(page);
+        let text = html::texts_of(&tokens, "article")
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| html::texts_of(&tokens, "body").concat());
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Outcome::TooHigh
+            } else if text.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait_secs: parse_wait(&text).unwrap_or(60),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown { message: text }
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+/// Seconds in a text like `You have 1m 23s left to wait.`
+fn parse_wait(text: &str) -> Option<u64> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    text[start..end]
+        .split_whitespace()
+        .try_fold(0, |total, amount| {
+            let unit = amount.len().checked_sub(1)?;
+            let value: u64 = amount[..unit].parse().ok()?;
+            let scale = match &amount[unit..] {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(total + value * scale)
+        })
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait_secs } => {
+                write!(
+                    f,
+                    "not checked, wait {}s before submitting again",
+                    wait_secs
+                )
+            }
+            Outcome::AlreadySolved => write!(f, "not checked, the part is solved already"),
+            Outcome::Unknown { message } => write!(f, "unknown response: {}", message),
+        }
+    }
+}
+
+/// One submitted answer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u32,
+    pub part: u8,
+    pub answer: Answer,
+    /// Seconds since the Unix epoch
+    pub submitted_at: u64,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+/// Every answer submitted so far, oldest first.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Load the history from `path`. A missing file means nothing was submitted yet.
+    pub fn load(path: &str) -> AocResult<History> {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|err| {
+                AocError::at(err.line(), err.column(), err.to_string()).with_file(path)
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(AocError::io("read", path, err)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> AocResult<()> {
+        let text = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, text + "\n").map_err(|err| AocError::io("write", path, err))
+    }
+
+    fn attempts(&self, day: u32, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part.number())
+    }
+
+    /// Reason not to submit `answer` at all: the part is solved, the answer is known to be
+    /// wrong, or the website still asks to wait.
+    fn refusal(&self, day: u32, part: Part, answer: &Answer, now: u64) -> Option<String> {
+        for attempt in self.attempts(day, part) {
+            if attempt.outcome == Outcome::Correct {
+                return Some(format!("already solved with answer {}", attempt.answer));
+            }
+            if attempt.answer == *answer && attempt.outcome.is_wrong() {
+                return Some(format!(
+                    "{} was submitted before and is {}",
+                    answer, attempt.outcome
+                ));
+            }
+        }
+        let last = self.attempts.last()?;
+        match last.outcome {
+            Outcome::RateLimited { wait_secs } if last.submitted_at + wait_secs > now => {
+                Some(format!(
+                    "rate limited, wait {}s before submitting again",
+                    last.submitted_at + wait_secs - now
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Warnings for `answer` lying outside of the too high and too low bounds recorded so far.
+    fn bound_warnings(&self, day: u32, part: Part, answer: &Answer) -> Vec<String> {
+        let Answer::Integer(value) = *answer else {
+            return Vec::new();
+        };
+        let bounds = |outcome: Outcome| {
+            self.attempts(day, part)
+                .filter(move |attempt| attempt.outcome == outcome)
+                .filter_map(|attempt| match attempt.answer {
+                    Answer::Integer(bound) => Some(bound),
+                    Answer::Text(_) => None,
+                })
+        };
+        let mut warnings = Vec::new();
+        if let Some(high) = bounds(Outcome::TooHigh).min().filter(|&high| value >= high) {
+            warnings.push(format!(
+                "{} is not below {}, which is too high",
+                value, high
+            ));
+        }
+        if let Some(low) = bounds(Outcome::TooLow).max().filter(|&low| value <= low) {
+            warnings.push(format!("{} is not above {}, which is too low", value, low));
+        }
+        warnings
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Solve the part of the day from the puzzle input, without printing anything. Fails for
+/// parts that still give the `UNSOLVED` placeholder.
+fn solve(registry: &Registry, args: &SubmitArgs) -> AocResult<Answer> {
+    let solution = registry
+        .get(args.day)
+        .ok_or_else(|| AocError::new(format!("day {} is not implemented", args.day)))?;
+    let input = InputSource::File(default_input_path(&args.inputs, args.day)).read()?;
+    let day_run = solution
+        .run(&input.text, &[args.part])
+        .map_err(|err| err.with_file(&input.name))?;
+    let answer = day_run.answers[0].answer.clone();
+    if answer.is_unsolved() {
+        return Err(AocError::new(format!(
+            "day {} part {} is not solved yet, it still answers the placeholder `{}`",
+            args.day, args.part, UNSOLVED
+        )));
+    }
+    Ok(answer)
+}
+
+fn submit_answer(registry: &Registry, args: &SubmitArgs) -> AocResult<Outcome> {
+    let answer = solve(registry, args)?;
+    let mut history = History::load(&args.history)?;
+    if let Some(reason) = history.refusal(args.day, args.part, &answer, now()) {
+        return Err(AocError::new(format!(
+            "not submitting day {} part {}: {}",
+            args.day, args.part, reason
+        )));
+    }
+    for warning in history.bound_warnings(args.day, args.part, &answer) {
+        eprintln!("warning: {}", warning);
+    }
+
+    let client = Client::from_args(&args.remote)?;
+    let page = client.submit_answer(args.day, args.part, &answer)?;
+    let outcome = Outcome::parse(&page);
+    println!(
+        "Day {} part {}: {} is {}",
+        args.day, args.part, answer, outcome
+    );
+    history.attempts.push(Attempt {
+        day: args.day,
+        part: args.part.number(),
+        answer,
+        submitted_at: now(),
+        outcome: outcome.clone(),
+    });
+    history.save(&args.history)?;
+    Ok(outcome)
+}
+
+/// Solve a part of a day and submit the answer, recording the outcome in the history.
+/// Exits with an error unless the answer is correct or the part was solved before.
+pub fn submit(registry: &Registry, args: &SubmitArgs) {
+    match submit_answer(registry, args) {
+        Ok(Outcome::Correct | Outcome::AlreadySolved) => {}
+        Ok(_) => exit(1),
+        Err(err) => {
+            eprintln!("{}", err.render(None));
+            exit(1);
+        }
+    }
+}
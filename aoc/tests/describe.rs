@@ -0,0 +1,98 @@
+mod common;
+
+use common::*;
+use std::fs;
+
+const PART1: &str = r#"<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is always present for the big Christmas sleigh launch.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul>
+<li>The smallest number in the left list is <code>1</code>.</li>
+<li>The total is <code><em>11</em></code>!</li>
+</ul>
+<p>To begin, <a href="/2024/day/1/input" target="_blank">get your puzzle input</a>. <span title="hover">What&apos;s</span> the total &amp; distance?</p>
+</article>
+"#;
+
+const PART2: &str = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>This time, you'll need to figure out <em>similarity</em>.</p>
+</article>
+"#;
+
+fn page(parts: &[&str]) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><head><script>var x = '<article>';</script></head><body><main>\n{}<p>Your puzzle answer was <code>11</code>.</p></main></body></html>\n",
+        parts.concat()
+    )
+}
+
+#[test]
+fn describe_saves_markdown_and_adds_part_two() {
+    let server = MockServer::start(|request| match request.header("cookie") {
+        Some(_) => (200, page(&[PART1, PART2])),
+        None => (200, page(&[PART1])),
+    });
+    let dir = scratch_dir("describe");
+    fs::create_dir_all(dir.join("day1")).unwrap();
+    let args = ["describe", "1", "--base-url", &server.url];
+    let readme = dir.join("day1/README.md");
+
+    let output = aoc(&dir, &args, None);
+    assert!(output.status.success(), "{:?}", output);
+    let expected = format!(
+        "<!-- Puzzle description from {}/2024/day/1, updated by `aoc describe` -->
+
+## --- Day 1: Historian Hysteria ---
+
+The *Chief Historian* is always present for the big Christmas sleigh launch.
+
+For example:
+
+```
+3   4
+4   3
+```
+
+- The smallest number in the left list is `1`.
+- The total is **`11`**!
+
+To begin, [get your puzzle input]({}/2024/day/1/input). What's the total & distance?
+",
+        server.url, server.url
+    );
+    assert_eq!(fs::read_to_string(&readme).unwrap(), expected);
+
+    let output = aoc(&dir, &args, Some("s"));
+    assert!(output.status.success(), "{:?}", output);
+    let both = fs::read_to_string(&readme).unwrap();
+    assert!(both.starts_with(&expected));
+    assert!(both.ends_with(
+        "\n## --- Part Two ---\n\nThis time, you'll need to figure out *similarity*.\n"
+    ));
+
+    // a page without part 2 does not replace the full description
+    let output = aoc(&dir, &args, None);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(fs::read_to_string(&readme).unwrap(), both);
+}
+
+#[test]
+fn describe_keeps_hand_written_readmes() {
+    let server = MockServer::start(|_| (200, page(&[PART1])));
+    let dir = scratch_dir("describe-hand-written");
+    fs::create_dir_all(dir.join("day1")).unwrap();
+    fs::write(dir.join("day1/README.md"), "# My notes\n").unwrap();
+
+    let output = aoc(&dir, &["describe", "1", "--base-url", &server.url], None);
+    assert!(!output.status.success());
+    assert_eq!(
+        fs::read_to_string(dir.join("day1/README.md")).unwrap(),
+        "# My notes\n"
+    );
+    assert!(server.requests().is_empty());
+
+    let output = aoc(&dir, &["describe", "2", "--base-url", &server.url], None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("aoc new 2"));
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use common::*;
+use std::fs;
+
+const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+</code></pre>
+<p>The smallest number in the left list is <code>1</code>, the distance is <em>not</em> <code>0</code>.</p>
+<p>In the example above, this is <code>2 + 1 + 0 + 1 + 2 + 5</code>, a total distance of <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Here are the same example lists again:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>The first number, <code>3</code>, appears <em>three</em> times, so the score is <em><code>9</code></em>.</p>
+<p>So, for these example lists, the similarity score at the end of this process is <code><em>31</em></code>.</p>
+</article>
+</main></body></html>
+"#;
+
+const ANSWERS: &str = "# Known correct answers.
+
+[day1.example]
+part1 = 11
+part2 = 31
+
+[day3.example]
+part1 = 161
+";
+
+#[test]
+fn examples_lists_and_stores_examples_with_answers() {
+    let dir = scratch_dir("examples");
+    fs::write(dir.join("page.html"), PAGE).unwrap();
+    fs::write(dir.join("answers.toml"), ANSWERS).unwrap();
+
+    let output = aoc(&dir, &["examples", "1", "--page", "page.html"], None);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[1] part 1, 6 lines"), "{}", stdout);
+    assert!(stdout.contains("[2] part 2, 2 lines"), "{}", stdout);
+    assert!(stdout.contains("part 1: 11"), "{}", stdout);
+    assert!(stdout.contains("part 2: 9, 31"), "{}", stdout);
+
+    let args = [
+        "examples",
+        "1",
+        "--page",
+        "page.html",
+        "--pick",
+        "1",
+        "--name",
+        "example2",
+    ];
+    let output = aoc(&dir, &args, None);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        fs::read_to_string(dir.join("examples/day1/example2.txt")).unwrap(),
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("answers.toml")).unwrap(),
+        "# Known correct answers.
+
+[day1.example]
+part1 = 11
+part2 = 31
+
+[day1.example2]
+part1 = 11
+part2 = 31
+
+[day3.example]
+part1 = 161
+"
+    );
+
+    // an example of part 2 only gets the answer of part 2, unless given explicitly
+    let args = [
+        "examples",
+        "1",
+        "--page",
+        "page.html",
+        "--pick",
+        "2",
+        "--name",
+        "short",
+    ];
+    let output = aoc(&dir, &args, None);
+    assert!(output.status.success(), "{:?}", output);
+    let answers = fs::read_to_string(dir.join("answers.toml")).unwrap();
+    assert!(
+        answers.contains("[day1.short]\npart2 = 31\n\n[day3.example]"),
+        "{}",
+        answers
+    );
+
+    // existing examples are never overwritten
+    let output = aoc(&dir, &args, None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("exists already"));
+}
+
+#[test]
+fn examples_downloads_the_page_and_appends_new_days() {
+    let server = MockServer::start(|_| (200, PAGE.to_string()));
+    let dir = scratch_dir("examples-download");
+    fs::write(dir.join("answers.toml"), ANSWERS).unwrap();
+    // the placeholder left by `aoc new`
+    fs::create_dir_all(dir.join("examples/day9")).unwrap();
+    fs::write(dir.join("examples/day9/example.txt"), "").unwrap();
+
+    let args = [
+        "examples",
+        "9",
+        "--pick",
+        "1",
+        "--part1",
+        "12",
+        "--base-url",
+        &server.url,
+    ];
+    let output = aoc(&dir, &args, None);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(server.requests()[0].path, "/2024/day/9");
+    assert!(fs::read_to_string(dir.join("examples/day9/example.txt"))
+        .unwrap()
+        .starts_with("3   4\n"));
+    let answers = fs::read_to_string(dir.join("answers.toml")).unwrap();
+    assert!(
+        answers.ends_with("part1 = 161\n\n[day9.example]\npart1 = 12\npart2 = 31\n"),
+        "{}",
+        answers
+    );
+}
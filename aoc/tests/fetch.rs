@@ -0,0 +1,50 @@
+mod common;
+
+use common::*;
+use std::fs;
+
+#[test]
+fn fetch_downloads_once_with_session_and_user_agent() {
+    let server = MockServer::start(|_| (200, "1 2\n3 4\n".to_string()));
+    let dir = scratch_dir("fetch");
+    fs::write(dir.join("aoc.toml"), "contact = \"me@example.com\"\n").unwrap();
+    let args = ["fetch", "3", "--base-url", &server.url];
+
+    let output = aoc(&dir, &args, Some("secret"));
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        fs::read_to_string(dir.join("inputs/day3.txt")).unwrap(),
+        "1 2\n3 4\n"
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2024/day/3/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+    let user_agent = requests[0].header("user-agent").unwrap();
+    assert!(user_agent.starts_with("aoc2024-runner/"), "{}", user_agent);
+    assert!(user_agent.contains("me@example.com"), "{}", user_agent);
+
+    // the cached input is never downloaded again
+    let output = aoc(&dir, &args, Some("secret"));
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn fetch_fails_without_session_or_on_error_status() {
+    let server = MockServer::start(|_| (404, "Not unlocked yet.\n".to_string()));
+    let dir = scratch_dir("fetch-errors");
+    let args = ["fetch", "25", "--base-url", &server.url];
+
+    let output = aoc(&dir, &args, None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no session token"));
+    assert!(server.requests().is_empty());
+
+    let output = aoc(&dir, &args, Some("secret"));
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("status 404"), "{}", stderr);
+    assert!(!dir.join("inputs/day25.txt").exists());
+}
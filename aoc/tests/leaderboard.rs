@@ -0,0 +1,112 @@
+mod common;
+
+use common::*;
+use serde_json::Value;
+use std::fs;
+
+const LEADERBOARD: &str = r#"{
+  "owner_id": 1,
+  "event": "2024",
+  "members": {
+    "1": {
+      "id": 1, "name": "Alice", "stars": 3, "local_score": 10, "global_score": 0,
+      "last_star_ts": 1733115600,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1733029391, "star_index": 1},
+              "2": {"get_star_ts": 1733029842, "star_index": 2}},
+        "2": {"1": {"get_star_ts": 1733115600, "star_index": 5}}
+      }
+    },
+    "7": {
+      "id": 7, "name": null, "stars": 2, "local_score": 12, "global_score": 0,
+      "last_star_ts": 1733040000,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1733030000, "star_index": 3},
+              "2": {"get_star_ts": 1733040000, "star_index": 4}}
+      }
+    },
+    "9": {
+      "id": 9, "name": "Idle", "stars": 0, "local_score": 0, "global_score": 0,
+      "last_star_ts": 0, "completion_day_level": {}
+    }
+  }
+}"#;
+
+#[test]
+fn leaderboard_renders_standings_from_a_file() {
+    let dir = scratch_dir("leaderboard");
+    fs::write(dir.join("board.json"), LEADERBOARD).unwrap();
+
+    let output = aoc(&dir, &["leaderboard", "--file", "board.json"], None);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines[3],
+        "|    1 | (anonymous user #7) |     2 |    12 | 2024-12-01 08:00:00 |"
+    );
+    assert_eq!(
+        lines[4],
+        "|    2 | Alice               |     3 |    10 | 2024-12-02 05:00:00 |"
+    );
+    assert!(lines[5].starts_with("|    3 | Idle "), "{}", stdout);
+    assert!(
+        stdout.contains(
+            "|   1 | Alice               | 2024-12-01 05:03:11 | 2024-12-01 05:10:42 |      7m 31s |"
+        ),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("|   1 | (anonymous user #7) |"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("2h 46m 40s"), "{}", stdout);
+
+    let args = ["leaderboard", "--file", "board.json", "--format", "json"];
+    let output = aoc(&dir, &args, None);
+    assert!(output.status.success(), "{:?}", output);
+    let standings: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(standings[1]["name"], "Alice");
+    assert_eq!(standings[1]["rank"], 2);
+    assert_eq!(standings[1]["days"][0]["delta_secs"], 451);
+    assert_eq!(standings[1]["days"][1]["part2_ts"], Value::Null);
+    assert_eq!(standings[2]["last_star_ts"], Value::Null);
+}
+
+#[test]
+fn leaderboard_downloads_into_the_cache() {
+    let server = MockServer::start(|_| (200, LEADERBOARD.to_string()));
+    let dir = scratch_dir("leaderboard-download");
+    fs::write(dir.join("aoc.toml"), "leaderboard = \"1\"\n").unwrap();
+    let args = [
+        "leaderboard",
+        "--format",
+        "ndjson",
+        "--base-url",
+        &server.url,
+    ];
+
+    let output = aoc(&dir, &args, Some("s"));
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout).lines().count(), 3);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2024/leaderboard/private/view/1.json");
+    assert_eq!(requests[0].header("cookie"), Some("session=s"));
+    assert!(dir.join(".cache/leaderboard-2024-1.json").is_file());
+
+    // a fresh cache is used instead of downloading again
+    let output = aoc(&dir, &args, Some("s"));
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(server.requests().len(), 1);
+
+    let output = aoc(
+        &dir,
+        &["leaderboard", "--id", "2", "--base-url", &server.url],
+        None,
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no session token"));
+}
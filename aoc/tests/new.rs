@@ -0,0 +1,64 @@
+mod common;
+
+use common::*;
+use std::fs;
+
+#[test]
+fn new_scaffolds_and_registers_a_day_once() {
+    let dir = scratch_dir("new");
+    fs::create_dir_all(dir.join("shared")).unwrap();
+    fs::create_dir_all(dir.join("aoc/src")).unwrap();
+    fs::write(
+        dir.join("aoc/Cargo.toml"),
+        "[dependencies]\nday1 = { path = \"../day1\" }\nserde = \"1.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("aoc/src/lib.rs"),
+        "pub fn registry() -> Registry {\n    registry.register::<day1::Day1>();\n}\n",
+    )
+    .unwrap();
+
+    let output = aoc(&dir, &["new", "2"], None);
+    assert!(output.status.success(), "{:?}", output);
+    let lib = fs::read_to_string(dir.join("day2/src/lib.rs")).unwrap();
+    assert!(lib.contains("impl Solution for Day2"), "{}", lib);
+    assert!(lib.contains("shared::example_tests!(Day2);"), "{}", lib);
+    assert!(fs::read_to_string(dir.join("day2/Cargo.toml"))
+        .unwrap()
+        .contains("name = \"day2\""));
+    assert_eq!(
+        fs::read_to_string(dir.join("examples/day2/example.txt")).unwrap(),
+        ""
+    );
+    let manifest = fs::read_to_string(dir.join("aoc/Cargo.toml")).unwrap();
+    assert_eq!(
+        manifest,
+        "[dependencies]\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\nserde = \"1.0\"\n"
+    );
+    let registry = fs::read_to_string(dir.join("aoc/src/lib.rs")).unwrap();
+    assert!(registry.contains("Day1>();\n    registry.register::<day2::Day2>();\n"));
+
+    // an existing day is never overwritten, nor registered twice
+    fs::write(dir.join("day2/src/lib.rs"), "// solved\n").unwrap();
+    let output = aoc(&dir, &["new", "2"], None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("refusing to overwrite"));
+    assert_eq!(
+        fs::read_to_string(dir.join("day2/src/lib.rs")).unwrap(),
+        "// solved\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("aoc/Cargo.toml")).unwrap(),
+        manifest
+    );
+}
+
+#[test]
+fn new_needs_the_workspace_root() {
+    let dir = scratch_dir("new-root");
+    let output = aoc(&dir, &["new", "2"], None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("is not the root of the workspace"));
+    assert!(!dir.join("day2").exists());
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::*;
+use std::fs;
+
+#[test]
+fn check_fails_when_nothing_could_be_checked() {
+    let dir = scratch_dir("run-check-missing");
+
+    let output = aoc(&dir, &["run", "--all", "--check"], None);
+    assert!(!output.status.success(), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(": 0 passed, 0 failed, 0 unknown,"),
+        "{}",
+        stderr
+    );
+
+    // without --check, running all days still tolerates missing inputs
+    let output = aoc(&dir, &["run", "--all"], None);
+    assert!(output.status.success(), "{:?}", output);
+}
+
+#[test]
+fn check_passes_and_fails_against_answers() {
+    let dir = scratch_dir("run-check");
+    fs::create_dir_all(dir.join("examples/day1")).unwrap();
+    fs::write(
+        dir.join("examples/day1/example.txt"),
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("answers.toml"),
+        "[day1.example]\npart1 = 11\npart2 = 31\n",
+    )
+    .unwrap();
+
+    let args = ["run", "1", "--example", "1", "--check"];
+    let output = aoc(&dir, &args, None);
+    assert!(output.status.success(), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Checked 2 answers: 2 passed, 0 failed, 0 unknown, 0 without"),
+        "{}",
+        stderr
+    );
+
+    fs::write(dir.join("answers.toml"), "[day1.example]\npart1 = 12\n").unwrap();
+    let output = aoc(&dir, &args, None);
+    assert!(!output.status.success(), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("0 passed, 1 failed, 1 unknown"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn check_matches_the_puzzle_input_however_its_path_is_spelled() {
+    let dir = scratch_dir("run-check-input-path");
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::write(
+        dir.join("inputs/day1.txt"),
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("answers.toml"),
+        "[day1.input]\npart1 = 11\npart2 = 31\n",
+    )
+    .unwrap();
+
+    for input in ["./inputs/day1.txt", "inputs/../inputs/day1.txt"] {
+        let output = aoc(&dir, &["run", "1", "--input", input, "--check"], None);
+        assert!(output.status.success(), "{:?}", output);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains(": 2 passed,"), "{}", stderr);
+    }
+
+    // stdin has no key, so its answers can't be checked
+    let output = aoc(&dir, &["run", "1", "--input", "-", "--check"], None);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(": 0 passed, 0 failed, 2 unknown,"),
+        "{}",
+        stderr
+    );
+}
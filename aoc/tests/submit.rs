@@ -0,0 +1,107 @@
+mod common;
+
+use common::*;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+fn page(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        message
+    )
+}
+
+/// Scratch directory with the day 1 example as the puzzle input, whose answers are 11 and 31.
+fn workspace(name: &str) -> std::path::PathBuf {
+    let dir = scratch_dir(name);
+    let example = Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples/day1/example.txt");
+    fs::create_dir_all(dir.join("inputs")).unwrap();
+    fs::copy(example, dir.join("inputs/day1.txt")).unwrap();
+    dir
+}
+
+fn history(dir: &Path) -> Vec<Value> {
+    let text = fs::read_to_string(dir.join("submissions.json")).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn submit_records_outcomes_and_refuses_known_wrong_answers() {
+    let server = MockServer::start(|request| {
+        let message = if request.body.contains("level=1") {
+            "That's not the right answer; your answer is too high. Please wait one minute."
+        } else {
+            "That's the right answer! You are <em>one gold star</em> closer."
+        };
+        (200, page(message))
+    });
+    let dir = workspace("submit");
+    // a too low answer of part 2 recorded earlier, which 31 does not respect
+    fs::write(
+        dir.join("submissions.json"),
+        r#"[{"day": 1, "part": 2, "answer": 40, "submitted_at": 0, "outcome": "too_low"}]"#,
+    )
+    .unwrap();
+
+    let output = aoc(
+        &dir,
+        &["submit", "1", "1", "--base-url", &server.url],
+        Some("s"),
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("11 is wrong, too high"));
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2024/day/1/answer");
+    assert_eq!(requests[0].body, "level=1&answer=11");
+    assert_eq!(requests[0].header("cookie"), Some("session=s"));
+    let attempts = history(&dir);
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[1]["answer"], 11);
+    assert_eq!(attempts[1]["outcome"], "too_high");
+
+    // the same wrong answer is not submitted again
+    let output = aoc(
+        &dir,
+        &["submit", "1", "1", "--base-url", &server.url],
+        Some("s"),
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("11 was submitted before"), "{}", stderr);
+    assert_eq!(server.requests().len(), 1);
+
+    let output = aoc(
+        &dir,
+        &["submit", "1", "2", "--base-url", &server.url],
+        Some("s"),
+    );
+    assert!(output.status.success(), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("31 is not above 40"), "{}", stderr);
+    assert_eq!(history(&dir)[2]["outcome"], "correct");
+}
+
+#[test]
+fn submit_waits_out_rate_limits() {
+    let server = MockServer::start(|_| {
+        let message = "You gave an answer too recently; you have to wait after submitting an \
+                       answer before trying again.  You have 1m 5s left to wait.";
+        (200, page(message))
+    });
+    let dir = workspace("submit-rate-limit");
+    let args = ["submit", "1", "2", "--base-url", &server.url];
+
+    let output = aoc(&dir, &args, Some("s"));
+    assert!(!output.status.success());
+    let attempts = history(&dir);
+    assert_eq!(attempts[0]["outcome"], "rate_limited");
+    assert_eq!(attempts[0]["wait_secs"], 65);
+
+    let output = aoc(&dir, &args, Some("s"));
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("rate limited, wait"));
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,48 @@
+use shared::*;
+
+pub struct Day1;
+
+fn sim_score_for_number(number: i32, col2_counter: &Counter<i32>) -> i32 {
+    number * (col2_counter.get(&number) as i32)
+}
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    type Input = (Vec<i32>, Vec<i32>);
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        let pairs = parse_lines(input, |(line_no, line)| {
+            let [item1, item2] = parse_columns::<i32, 2>(line_no, line)?;
+            Ok((item1, item2))
+        })?;
+        Ok(pairs.into_iter().unzip())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        let (mut col1, mut col2) = input.clone();
+        col1.sort_unstable();
+        col2.sort_unstable();
+        let pairs: Vec<(i32, i32)> = col1.into_iter().zip(col2).collect();
+        // dbg!(&pairs);
+        pairs.into_iter().map(|(a, b)| (a - b).abs()).sum()
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        let (col1, col2) = input;
+        let mut col1 = col1.clone();
+        col1.sort_unstable();
+
+        let col2_counter: Counter<i32> = col2.iter().copied().collect();
+        let scores: Vec<i32> = col1
+            .into_iter()
+            .map(|v| sim_score_for_number(v, &col2_counter))
+            .collect();
+
+        scores.into_iter().sum::<i32>()
+    }
+}
+
+shared::example_tests!(Day1);
@@ -0,0 +1,82 @@
+use shared::*;
+
+pub struct Day2;
+
+fn remove_at<T>(vec: &[T], index: usize) -> Vec<T>
+where
+    T: Clone,
+{
+    [&vec[..index], &vec[index + 1..]].concat()
+}
+
+fn check_conditions_asc(linenumbers: &[i32]) -> (bool, Option<usize>) {
+    for (idx, w) in linenumbers.windows(2).enumerate() {
+        let d = w[1] - w[0];
+        // if NOT ASC then
+        if d <= 0 {
+            return (false, Some(idx));
+        }
+        let absdiff = d.abs();
+        if !(1..=3).contains(&absdiff) {
+            return (false, Some(idx));
+        }
+    }
+
+    (true, None)
+}
+
+fn line_is_safe(linenumbers: &[i32], with_tolerance: bool) -> bool {
+    let linenumbers_rev = linenumbers.iter().rev().copied().collect::<Vec<_>>();
+
+    // Check original line
+    let (success, v_idx) = check_conditions_asc(linenumbers);
+    let (success_rev, v_idx_rev) = check_conditions_asc(&linenumbers_rev);
+    if success || success_rev {
+        true
+    } else if with_tolerance {
+        let (v_idx, v_idx_rev) = (v_idx.unwrap(), v_idx_rev.unwrap());
+
+        // first, test by removing the first index of the window
+        let l1 = remove_at(linenumbers, v_idx);
+        let l2_rev = remove_at(&linenumbers_rev, v_idx_rev);
+        let (success, _) = check_conditions_asc(&l1);
+        let (success_rev, _) = check_conditions_asc(&l2_rev);
+
+        // then, test by removing second index of the window
+        let l1_offset = remove_at(linenumbers, v_idx + 1);
+        let l2_offset_rev = remove_at(&linenumbers_rev, v_idx_rev + 1);
+        let (success_offset, _) = check_conditions_asc(&l1_offset);
+        let (success_offset_rev, _) = check_conditions_asc(&l2_offset_rev);
+        success || success_rev || success_offset || success_offset_rev
+    } else {
+        false
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Vec<i32>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_lines(input, |(line_no, line)| parse_words(line_no, line))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input
+            .iter()
+            .filter(|linenumbers| line_is_safe(linenumbers, false))
+            .count()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input
+            .iter()
+            .filter(|linenumbers| line_is_safe(linenumbers, true))
+            .count()
+    }
+}
+
+shared::example_tests!(Day2);
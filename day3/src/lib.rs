@@ -0,0 +1,95 @@
+use regex::Regex;
+use shared::*;
+
+pub struct Day3;
+
+/// A `mul(left,right)` instruction found in the corrupted memory.
+#[derive(Debug)]
+pub struct Mul {
+    offset: usize,
+    left: i32,
+    right: i32,
+}
+
+/// The corrupted memory, with the valid `mul` instructions extracted.
+pub struct Memory {
+    muls: Vec<Mul>,
+    /// Byte offsets where instructions are enabled.
+    enabled: IntervalSet<usize>,
+}
+
+/// Byte ranges of `input` which are enabled, i.e. not between a `don't()` and the next `do()`.
+fn enabled_regions(input: &str) -> IntervalSet<usize> {
+    let re_enable = Regex::new(r"don't\(\)|do\(\)").unwrap();
+    let mut enabled = IntervalSet::new();
+    enabled.insert(0..input.len());
+    let mut disabled_from = None;
+
+    for mat in re_enable.find_iter(input) {
+        match (mat.as_str(), disabled_from) {
+            ("don't()", None) => disabled_from = Some(mat.start()),
+            ("do()", Some(start)) => {
+                enabled.remove(start..mat.end());
+                disabled_from = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(start) = disabled_from {
+        enabled.remove(start..input.len());
+    }
+
+    enabled
+}
+
+fn parse_memory(input: &str) -> AocResult<Memory> {
+    let re_mul = Regex::new(r"mul\((-?\d+),(-?\d+)\)").unwrap();
+    let mut muls = Vec::new();
+
+    let mut line_start = 0;
+    for (i, line) in input.split('\n').enumerate() {
+        for caps in re_mul.captures_iter(line) {
+            let (_, [left, right]) = caps.extract();
+            muls.push(Mul {
+                offset: line_start + caps.get(0).map_or(0, |full| full.start()),
+                left: parse_token(i + 1, line, left)?,
+                right: parse_token(i + 1, line, right)?,
+            });
+        }
+        line_start += line.len() + 1;
+    }
+
+    // the enabled state is kept over lines, so look at the entire input at once
+    Ok(Memory {
+        muls,
+        enabled: enabled_regions(input),
+    })
+}
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Memory;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_memory(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        input.muls.iter().map(|mul| mul.left * mul.right).sum()
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        input
+            .muls
+            .iter()
+            .filter(|mul| input.enabled.contains(mul.offset))
+            .map(|mul| mul.left * mul.right)
+            .sum()
+    }
+}
+
+shared::example_tests!(Day3);
@@ -0,0 +1,97 @@
+use shared::*;
+
+pub struct Day4;
+
+// problem 1
+const START_CHAR1: char = 'X';
+const KEYWORD1: &str = "XMAS";
+const WORD_LEN1: usize = KEYWORD1.len();
+const STEPS1: isize = WORD_LEN1 as isize - 1;
+
+// problem 2
+const START_CHAR2: char = 'A';
+const KEYWORD2: &str = "MAS";
+const KEYWORD2_REV: &str = "SAM";
+
+/// Check if the keyword is found starting at `pos` in the given `direction`.
+fn check_direction(pos: Point, direction: Dir8, mat: &Grid<char>) -> usize {
+    // If the end position is outside the matrix boundaries, return 0 early.
+    if mat.step(pos, direction, STEPS1).is_none() {
+        return 0;
+    }
+
+    // Collect characters along this direction to form the candidate string.
+    let seq: String = std::iter::once(pos)
+        .chain(mat.ray(pos, direction))
+        .take(WORD_LEN1)
+        .map(|pos| mat[pos])
+        .collect();
+
+    if seq == KEYWORD1 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Look around a given position in all eight directions and return how many times KEYWORD is found.
+fn look_around1(pos: Point, mat: &Grid<char>) -> usize {
+    Dir8::ALL
+        .into_iter()
+        .map(|direction| check_direction(pos, direction, mat))
+        .sum()
+}
+
+/// Check if both diagonals crossing `pos` spell the keyword, in either direction.
+fn check_diagonals(pos: Point, mat: &Grid<char>) -> usize {
+    let upleft_char = mat.get_offset(pos, Dir8::UpLeft);
+    let upright_char = mat.get_offset(pos, Dir8::UpRight);
+    let downleft_char = mat.get_offset(pos, Dir8::DownLeft);
+    let downright_char = mat.get_offset(pos, Dir8::DownRight);
+    let diag1 = [
+        *upleft_char.unwrap_or(&'_'),
+        'A',
+        *downright_char.unwrap_or(&'_'),
+    ]
+    .iter()
+    .collect::<String>();
+    let diag2 = [
+        *upright_char.unwrap_or(&'_'),
+        'A',
+        *downleft_char.unwrap_or(&'_'),
+    ]
+    .iter()
+    .collect::<String>();
+    if (diag1 == KEYWORD2 || diag1 == KEYWORD2_REV) && (diag2 == KEYWORD2 || diag2 == KEYWORD2_REV)
+    {
+        1
+    } else {
+        0
+    }
+}
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Grid::parse(input)
+    }
+
+    fn part1(mat: &Self::Input) -> usize {
+        mat.positions_of(&START_CHAR1)
+            .map(|pos| look_around1(pos, mat))
+            .sum()
+    }
+
+    fn part2(mat: &Self::Input) -> usize {
+        mat.positions_of(&START_CHAR2)
+            .map(|pos| check_diagonals(pos, mat))
+            .sum()
+    }
+}
+
+shared::example_tests!(Day4);
@@ -0,0 +1,111 @@
+use shared::*;
+use std::collections::HashMap;
+
+pub struct Day5;
+
+/// Page ordering rules `X|Y` and the page updates to check against them.
+pub struct PrintQueue {
+    rules: Vec<(u32, u32)>,
+    updates: Vec<Vec<u32>>,
+}
+
+fn parse_print_queue(input: &str) -> AocResult<PrintQueue> {
+    // the rules and updates sections are separated by a blank line
+    let [rules, updates] = sections(input)
+        .try_into()
+        .map_err(|_| AocError::new("expected a blank line between the rules and the updates"))?;
+    let rules = rules.parse_lines(|(line_no, line)| parse_pair(line_no, line, "|"))?;
+    let updates = updates.parse_lines(|(line_no, line)| parse_separated(line_no, line, ","))?;
+    Ok(PrintQueue { rules, updates })
+}
+
+/// Whether the pages of `update` respect every rule which applies to them.
+fn is_ordered(update: &[u32], rules: &Graph<u32>) -> bool {
+    // Map each page of the update to its position for quick lookup
+    let position_map: HashMap<u32, usize> = update
+        .iter()
+        .enumerate()
+        .map(|(i, &page)| (page, i))
+        .collect();
+
+    // For each rule X|Y with both pages present in the update, X must come before Y.
+    rules.edges().all(
+        |(src, dst)| match (position_map.get(src), position_map.get(dst)) {
+            (Some(src_pos), Some(dst_pos)) => src_pos < dst_pos,
+            _ => true,
+        },
+    )
+}
+
+/// Sum of the middle page numbers of the given updates.
+fn sum_middle_pages<'a>(updates: impl Iterator<Item = &'a Vec<u32>>) -> u32 {
+    updates.map(|update| update[(update.len() - 1) / 2]).sum()
+}
+
+fn problem1(input: &PrintQueue) -> u32 {
+    let rules: Graph<u32> = input.rules.iter().copied().collect();
+    sum_middle_pages(
+        input
+            .updates
+            .iter()
+            .filter(|update| is_ordered(update, &rules)),
+    )
+}
+
+fn problem2(input: &PrintQueue) -> u32 {
+    let rules: Graph<u32> = input.rules.iter().copied().collect();
+    let corrected_updates: Vec<Vec<u32>> = input
+        .updates
+        .iter()
+        .filter(|update| !is_ordered(update, &rules))
+        .map(|update| fix_update(update, &rules))
+        .collect();
+
+    // Sum the middle page number of all corrected updates
+    sum_middle_pages(corrected_updates.iter())
+}
+
+/// Sort the pages of an update topologically by the rules that apply to them.
+/// This function returns a corrected ordering of the pages.
+fn fix_update(update_pages: &[u32], rules: &Graph<u32>) -> Vec<u32> {
+    // Build a subgraph for just the pages of this update which appear in the rules
+    let subgraph = rules.subgraph(|page| update_pages.contains(page));
+
+    let mut corrected = match subgraph.toposort() {
+        Ok(order) => order,
+        Err(cycle) => {
+            // If there's a cycle here, we can't fix it. For puzzle logic, we assume no such case.
+            // Just return the original line for safety.
+            if debug_enabled() {
+                eprintln!("cannot fix update {:?}: {}", update_pages, cycle);
+            }
+            return update_pages.to_vec();
+        }
+    };
+
+    // Append unconstrained pages at the end (or handle differently as per puzzle logic)
+    corrected.extend(update_pages.iter().filter(|&page| !subgraph.contains(page)));
+    corrected
+}
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = PrintQueue;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_print_queue(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        problem1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        problem2(input)
+    }
+}
+
+shared::example_tests!(Day5);
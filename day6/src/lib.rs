@@ -0,0 +1,186 @@
+use indicatif::ProgressIterator;
+use shared::*;
+use std::fmt;
+
+pub struct Day6;
+
+/// The lab map with the state of the guard walking through it.
+#[derive(Clone, Debug)]
+pub struct Lab {
+    /// The map without the guard: `.` for open cells, `#` and `O` for obstructions.
+    grid: Grid<char>,
+    iter: usize,
+    guard_pos: Point,
+    guard_direction: Direction,
+    /// Cells the guard has been on, with the directions it was facing there.
+    visited: DirectionBitGrid,
+}
+
+/// How a patrol of the guard ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    LeftGrid,
+    Loop,
+}
+
+impl Lab {
+    fn new(mut grid: Grid<char>) -> AocResult<Self> {
+        let guard_pos = grid
+            .find(|&cell| Direction::from_arrow(cell).is_some())
+            .ok_or_else(|| AocError::new("guard is not found in grid"))?;
+        let guard_direction = Direction::from_arrow(grid[guard_pos]).unwrap();
+        grid[guard_pos] = '.';
+        let mut visited = DirectionBitGrid::for_grid(&grid);
+        visited.insert(guard_pos, guard_direction);
+        Ok(Lab {
+            grid,
+            iter: 0,
+            guard_pos,
+            guard_direction,
+            visited,
+        })
+    }
+
+    fn guard_distinct_pos(&self) -> usize {
+        self.visited.positions().count()
+    }
+
+    /// Move the guard one step forward, or turn right if an obstruction is in the way.
+    /// Returns how the patrol ended once the guard leaves the grid or repeats itself.
+    fn step(&mut self) -> Option<Outcome> {
+        self.iter += 1;
+        let Some(next_pos) = self.grid.step(self.guard_pos, self.guard_direction, 1) else {
+            return Some(Outcome::LeftGrid);
+        };
+        match self.grid[next_pos] {
+            '#' | 'O' => self.guard_direction = self.guard_direction.turn_right(),
+            _ => self.guard_pos = next_pos,
+        }
+        // being at the same position facing the same direction again means a loop
+        if self.visited.insert(self.guard_pos, self.guard_direction) {
+            None
+        } else {
+            Some(Outcome::Loop)
+        }
+    }
+
+    /// Let the guard walk until it leaves the grid or gets stuck in a loop.
+    fn patrol(&mut self, debug: bool) -> Outcome {
+        if debug {
+            self.print_grid();
+        }
+        loop {
+            let outcome = self.step();
+            if debug {
+                self.print_grid();
+            }
+            if let Some(outcome) = outcome {
+                return outcome;
+            }
+        }
+    }
+
+    fn print_grid(&self) {
+        eprintln!(
+            "Grid at iteration {} with {} distinct guard positions:",
+            self.iter,
+            self.guard_distinct_pos()
+        );
+        eprint!("{}", self);
+    }
+}
+
+/// Draw the lab with the guard, and the cells it visited as `|` or `-` by the direction it
+/// walked them in, or `+` for both.
+impl fmt::Display for Lab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.grid.rows() {
+            for col in 0..self.grid.cols() {
+                let pos = Point::from_index(row, col);
+                let (vertical, horizontal) = self.visited.directions(pos).fold(
+                    (false, false),
+                    |(vertical, horizontal), direction| {
+                        (
+                            vertical || direction.is_vertical(),
+                            horizontal || !direction.is_vertical(),
+                        )
+                    },
+                );
+                let cell = match (vertical, horizontal) {
+                    _ if pos == self.guard_pos => self.guard_direction.arrow(),
+                    (true, true) => '+',
+                    (true, false) => '|',
+                    (false, true) => '-',
+                    (false, false) => self.grid[pos],
+                };
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_grid(input: &str) -> AocResult<Lab> {
+    let grid = Grid::parse_with(input, |cell, (i, j)| {
+        if ['.', '#'].contains(&cell) || Direction::from_arrow(cell).is_some() {
+            Ok(cell)
+        } else {
+            Err(AocError::at(
+                i + 1,
+                j + 1,
+                format!("unexpected cell '{}' in grid", cell),
+            ))
+        }
+    })?;
+
+    Lab::new(grid)
+}
+
+/// Compute how many distinct positions the "guard" visits before leaving the grid
+fn problem1(input: &Lab, debug: bool) -> usize {
+    let mut lab = input.clone();
+    lab.patrol(debug);
+    lab.guard_distinct_pos()
+}
+
+/// Naively add in obstacles and detect which positions create a cycle
+fn problem2(input: &Lab, debug: bool) -> usize {
+    let mut obstruction_count = 0;
+    for i in (0..input.grid.rows()).progress() {
+        for j in 0..input.grid.cols() {
+            let pos = Point::from_index(i, j);
+            if input.grid[pos] != '.' || pos == input.guard_pos {
+                continue;
+            }
+            let mut lab = input.clone();
+            lab.grid[pos] = 'O';
+            if lab.patrol(debug) == Outcome::Loop {
+                obstruction_count += 1;
+            }
+        }
+    }
+    obstruction_count
+}
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type Input = Lab;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        problem1(input, debug_enabled())
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        problem2(input, debug_enabled())
+    }
+}
+
+shared::example_tests!(Day6);
@@ -0,0 +1,174 @@
+use colored::*;
+use indicatif::ParallelProgressIterator;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use shared::*;
+use std::sync::Arc;
+
+pub struct Day7;
+
+#[derive(Debug)]
+pub struct Equation {
+    test_value: i64,
+    numbers: Vec<i64>,
+}
+
+fn parse_equation(line_no: usize, line: &str) -> AocResult<Equation> {
+    let (test_value, numbers) = parse_key_values(line_no, line)?;
+    if numbers.is_empty() {
+        return Err(AocError::at_line(line_no, "equation has no numbers"));
+    }
+
+    Ok(Equation {
+        test_value,
+        numbers,
+    })
+}
+
+/// All ways to place operators between `n` numbers. Equations with the same amount of
+/// numbers share the result, so it is cached.
+#[memoize]
+fn generate_operator_permutations(
+    n: usize,
+    operators: &'static [&'static str],
+) -> Arc<Vec<Vec<&'static str>>> {
+    if n < 2 {
+        return Arc::new(vec![]);
+    }
+
+    let k = operators.len();
+    let total_combinations = k.pow((n - 1) as u32);
+    let mut permutations = Vec::with_capacity(total_combinations);
+
+    for i in 0..total_combinations {
+        let mut ops = Vec::with_capacity(n - 1);
+        let mut index = i;
+        for _ in 0..(n - 1) {
+            ops.push(operators[index % k]);
+            index /= k;
+        }
+        permutations.push(ops);
+    }
+
+    Arc::new(permutations)
+}
+
+/// Evaluate the expression left to right, `None` if an intermediate result overflows.
+fn evaluate_expression(numbers: &[i64], operators: &[&str]) -> Option<i64> {
+    let mut total = numbers[0]; // first number in the sequence is used as the initial accumulator value
+    for (i, operator) in operators.iter().enumerate() {
+        let num = numbers[i + 1];
+        total = match *operator {
+            "+" => total.checked_add(num)?,
+            "*" => total.checked_mul(num)?,
+            "||" => math::checked_concat(total, num)?,
+            _ => panic!("Unsupported operator"),
+        };
+    }
+    Some(total)
+}
+
+fn visualize_expression(numbers: &[i64], operators: &[&str], result: i64) -> String {
+    let mut expression = String::new();
+    expression.push_str(&numbers[0].to_string());
+
+    for (op, num) in operators.iter().zip(numbers.iter().skip(1)) {
+        let op_colored = match *op {
+            "+" => "+".green(),
+            "*" => "*".red(),
+            "||" => "||".yellow(),
+            _ => op.to_string().normal(),
+        };
+        expression.push_str(&format!(" {} {}", op_colored, num));
+    }
+
+    expression.push_str(&format!(" = {}", result));
+    expression
+}
+
+/// Sum of the test values of the equations that some combination of `operators` makes
+/// true. With `debug`, every such combination is printed to stderr as it is found.
+fn process_equations(
+    equations: &[Equation],
+    operators: &'static [&'static str],
+    debug: bool,
+) -> i64 {
+    equations
+        .par_iter()
+        .progress()
+        .map(|eq| {
+            let num_count = eq.numbers.len();
+
+            // Handle cases with only one number
+            if num_count == 1 {
+                if eq.numbers[0] != eq.test_value {
+                    return 0;
+                }
+                if debug {
+                    eprintln!("- {} = {}", eq.numbers[0], eq.test_value);
+                }
+                return eq.test_value;
+            }
+
+            let operator_permutations = generate_operator_permutations(num_count, operators);
+            let mut is_valid = false;
+
+            for ops in operator_permutations.iter() {
+                let result = evaluate_expression(&eq.numbers, ops);
+                if let Some(result) = result.filter(|&result| result == eq.test_value) {
+                    if !debug {
+                        // one valid combination is enough for the sum
+                        return eq.test_value;
+                    }
+                    is_valid = true;
+                    let expr = visualize_expression(&eq.numbers, ops, result);
+                    eprintln!("- {} = {}", expr, eq.test_value);
+                }
+            }
+
+            if is_valid {
+                eq.test_value
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn parse_equations(input: &str) -> AocResult<Vec<Equation>> {
+    parse_lines(input, |(line_no, line)| parse_equation(line_no, line))
+}
+
+fn solve(equations: &[Equation], operators: &'static [&'static str]) -> i64 {
+    // Print valid expressions on stderr, so the answer on stdout stays machine readable
+    let debug = debug_enabled();
+    if debug {
+        eprintln!("Valid Expressions:");
+    }
+    let sum = process_equations(equations, operators, debug);
+    if debug {
+        eprintln!("\nTotal Sum of Valid Test Values: {}", sum);
+    }
+    sum
+}
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = Vec<Equation>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        parse_equations(input)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        solve(input, &["*", "+"])
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        solve(input, &["*", "+", "||"])
+    }
+}
+
+shared::example_tests!(Day7);
@@ -0,0 +1,119 @@
+use shared::math::gcd;
+use shared::*;
+use std::collections::HashMap;
+
+pub struct Day8;
+
+fn input_to_map(grid: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    let mut ant_to_coords: HashMap<char, Vec<Point>> = HashMap::new();
+    grid.iter().for_each(|(pos, char)| match char {
+        '.' => {}
+        other => ant_to_coords.entry(*other).or_default().push(pos),
+    });
+    ant_to_coords
+}
+
+fn problem1(grid: &Grid<char>) -> usize {
+    let map = input_to_map(grid);
+    let mut antinodes_set = BitGrid::for_grid(grid);
+
+    // Iterate over all pairs of antennas with the same frequency
+    for positions in map.values() {
+        for (i, &p1) in positions.iter().enumerate() {
+            for &p2 in &positions[i + 1..] {
+                // Calculate the direction vector from antenna1 to antenna2
+                let delta = p2 - p1;
+
+                // Calculate the two antinodes
+                // Extend the line segment in both directions by twice the distance
+                let antinode1 = p1 - delta;
+                let antinode2 = p2 + delta;
+
+                // Check if antinodes are within bounds and add them to the set
+                if grid.in_bounds(antinode1) {
+                    antinodes_set.insert(antinode1);
+                }
+                if grid.in_bounds(antinode2) {
+                    antinodes_set.insert(antinode2);
+                }
+            }
+        }
+    }
+
+    // Count of unique antinodes
+    antinodes_set.count()
+}
+
+fn problem2(grid: &Grid<char>) -> usize {
+    let map = input_to_map(grid);
+    let mut antinodes_set = BitGrid::for_grid(grid);
+
+    // Iterate over all antenna frequencies
+    for positions in map.values() {
+        // Include all antenna positions themselves as antinodes
+        positions.iter().for_each(|&pos| {
+            antinodes_set.insert(pos);
+        });
+
+        for (i, &p1) in positions.iter().enumerate() {
+            for &p2 in &positions[i + 1..] {
+                // Calculate the direction vector between the two antennas
+                let delta = p2 - p1;
+
+                // Use the greatest common divisor to normalize the direction.
+                // Division by the gcd shared between the two direction vectors ensures
+                // the direction vector becomes the smallest step increment to add to some
+                // point p on the line to traverse to next point on the line
+                let step = delta / gcd(delta.row, delta.col);
+
+                // Extend the line in both directions to cover all collinear points
+                // Forward direction
+
+                // below works, because ALL collinear points along the line spanned by
+                // the two points p1, p2 can be written as:
+                // pn = p1 + k * step
+                // or in other direction:
+                // pn = p2 + k * step
+                let mut pos = p2 + step;
+
+                // "k" above is the iteration of the loop below
+                while grid.in_bounds(pos) {
+                    antinodes_set.insert(pos);
+                    pos += step;
+                }
+
+                // Backward direction
+                let mut pos = p1 - step;
+                while grid.in_bounds(pos) {
+                    antinodes_set.insert(pos);
+                    pos -= step;
+                }
+            }
+        }
+    }
+
+    // Count of unique antinodes
+    antinodes_set.count()
+}
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Grid::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        problem1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        problem2(input)
+    }
+}
+
+shared::example_tests!(Day8);
@@ -0,0 +1,142 @@
+use crate::{Answer, AocError, AocResult, Part};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+/// Known correct answers, as stored in `answers.toml`:
+///
+/// ```toml
+/// [day6.input]
+/// part1 = 41
+/// part2 = 6
+///
+/// [day6.example]
+/// part1 = 41
+/// ```
+///
+/// Days are keyed as `dayN`, inputs by their `Input::key` and parts as `partN`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExpectedAnswers {
+    days: BTreeMap<String, BTreeMap<String, BTreeMap<String, Answer>>>,
+}
+
+/// Result of comparing an answer against the expected one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl CheckStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "pass",
+            CheckStatus::Fail { .. } => "fail",
+            CheckStatus::Unknown => "unknown",
+        }
+    }
+}
+
+fn day_key(day: u32) -> String {
+    format!("day{}", day)
+}
+
+fn part_key(part: Part) -> String {
+    format!("part{}", part)
+}
+
+impl ExpectedAnswers {
+    /// Load the answers from `path`. A missing file means no answers are known yet.
+    pub fn load(path: &str) -> AocResult<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(AocError::io("read", path, err)),
+        };
+        Self::parse(&text).map_err(|err| err.with_file(path))
+    }
+
+    pub fn parse(text: &str) -> AocResult<Self> {
+        toml::from_str(text).map_err(|err| AocError::from_toml(text, &err))
+    }
+
+    pub fn get(&self, day: u32, input_key: &str, part: Part) -> Option<&Answer> {
+        self.days
+            .get(&day_key(day))?
+            .get(input_key)?
+            .get(&part_key(part))
+    }
+
+    pub fn insert(&mut self, day: u32, input_key: &str, part: Part, answer: Answer) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(input_key.to_string())
+            .or_default()
+            .insert(part_key(part), answer);
+    }
+
+    pub fn check(
+        &self,
+        day: u32,
+        input_key: Option<&str>,
+        part: Part,
+        answer: &Answer,
+    ) -> CheckStatus {
+        match input_key.and_then(|key| self.get(day, key, part)) {
+            Some(expected) if expected == answer => CheckStatus::Pass,
+            Some(expected) => CheckStatus::Fail {
+                expected: expected.clone(),
+            },
+            None => CheckStatus::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_against_known_answers() {
+        let expected =
+            ExpectedAnswers::parse("[day1.input]\npart1 = 11\n\n[day1.example]\npart2 = \"abc\"\n")
+                .unwrap();
+        let check = |key, part, answer: Answer| expected.check(1, key, part, &answer);
+        assert_eq!(
+            check(Some("input"), Part::One, 11.into()),
+            CheckStatus::Pass
+        );
+        assert_eq!(
+            check(Some("input"), Part::One, 12.into()),
+            CheckStatus::Fail {
+                expected: Answer::Integer(11)
+            }
+        );
+        assert_eq!(
+            check(Some("example"), Part::Two, "abc".into()),
+            CheckStatus::Pass
+        );
+        // numbers and text never match each other
+        assert!(matches!(
+            check(Some("example"), Part::Two, 0.into()),
+            CheckStatus::Fail { .. }
+        ));
+        assert_eq!(
+            check(Some("input"), Part::Two, 11.into()),
+            CheckStatus::Unknown
+        );
+        assert_eq!(
+            check(Some("other"), Part::One, 11.into()),
+            CheckStatus::Unknown
+        );
+        assert_eq!(check(None, Part::One, 11.into()), CheckStatus::Unknown);
+        assert_eq!(
+            expected.check(2, Some("input"), Part::One, &11.into()),
+            CheckStatus::Unknown
+        );
+    }
+}
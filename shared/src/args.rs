@@ -0,0 +1,287 @@
+use crate::{Answer, Part};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::str::FromStr;
+
+/// Arguments of the per day binaries.
+#[derive(Parser, Debug)]
+#[command(
+    version,
+    about = "Solve one part of the puzzle of the day",
+    long_about = None
+)]
+pub struct Args {
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    #[arg(short, long)]
+    pub problem: i32,
+
+    #[arg(short, long, action)]
+    pub debug: bool,
+}
+
+impl Args {
+    pub fn argparse() -> Args {
+        Args::parse()
+    }
+}
+
+/// Arguments of the `aoc` runner binary.
+#[derive(Parser, Debug)]
+#[command(
+    name = "aoc",
+    version,
+    about = "Run, benchmark and manage the Advent of Code 2024 solutions",
+    long_about = None
+)]
+pub struct Cli {
+    #[arg(short, long, action, global = true)]
+    pub debug: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+impl Cli {
+    pub fn argparse() -> Cli {
+        Cli::parse()
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run one day, one part of a day, or every registered day
+    Run(RunArgs),
+    /// Benchmark one day or one part of a day
+    Bench(BenchArgs),
+    /// Create the crate of a new day and register it with the runner
+    New(NewArgs),
+    /// Download the puzzle input of a day into the inputs directory
+    Fetch(FetchArgs),
+    /// Solve a part of a day and submit the answer to the website
+    Submit(SubmitArgs),
+    /// Save the puzzle description of a day as Markdown in `dayN/README.md`
+    Describe(DescribeArgs),
+    /// List the example inputs of a puzzle page, or store one with its expected answers
+    Examples(ExamplesArgs),
+    /// Show the standings of a private leaderboard
+    Leaderboard(LeaderboardArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct RunArgs {
+    /// Day to run
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    pub day: Option<u32>,
+
+    /// Part to run, both parts are run when omitted
+    pub part: Option<Part>,
+
+    /// Run every registered day
+    #[arg(long, action, conflicts_with = "input")]
+    pub all: bool,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// Output format of the answers
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// Verify the answers against the known correct ones, failing if any of them differ
+    #[arg(short, long, action)]
+    pub check: bool,
+
+    /// File with the known correct answers
+    #[arg(long, default_value = "answers.toml")]
+    pub answers: String,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human readable summary table
+    Text,
+    /// A single JSON array with one object per answer
+    Json,
+    /// One JSON object per answer per line
+    Ndjson,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct BenchArgs {
+    /// Day to benchmark
+    pub day: u32,
+
+    /// Part to benchmark, both parts are benchmarked when omitted
+    pub part: Option<Part>,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// Number of untimed runs before measuring
+    #[arg(short, long, default_value_t = 3)]
+    pub warmup: usize,
+
+    /// Number of timed runs
+    #[arg(short = 'n', long, default_value_t = 10)]
+    pub iterations: usize,
+
+    /// File with baseline timings to compare against
+    #[arg(short, long, default_value = "benchmarks.json")]
+    pub baseline: String,
+
+    /// Store the timings of this run as the new baseline
+    #[arg(short, long, action)]
+    pub save: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct NewArgs {
+    /// Day to create
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// Root directory of the workspace
+    #[arg(long, default_value = ".")]
+    pub root: String,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct FetchArgs {
+    /// Day to download the input of
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// Directory to store the input in, as `<INPUTS>/dayN.txt`
+    #[arg(long, default_value = "inputs")]
+    pub inputs: String,
+
+    #[command(flatten)]
+    pub remote: RemoteArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct SubmitArgs {
+    /// Day to submit
+    pub day: u32,
+
+    /// Part to submit
+    pub part: Part,
+
+    /// Directory containing the puzzle inputs
+    #[arg(long, default_value = "inputs")]
+    pub inputs: String,
+
+    /// File recording every submitted answer and its outcome
+    #[arg(long, default_value = "submissions.json")]
+    pub history: String,
+
+    #[command(flatten)]
+    pub remote: RemoteArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct DescribeArgs {
+    /// Day to describe
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// Root directory of the workspace
+    #[arg(long, default_value = ".")]
+    pub root: String,
+
+    #[command(flatten)]
+    pub remote: RemoteArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ExamplesArgs {
+    /// Day to extract examples of
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// Saved puzzle page to read instead of downloading it
+    #[arg(long)]
+    pub page: Option<String>,
+
+    /// Number of the example to store, from the list printed without this option
+    #[arg(long)]
+    pub pick: Option<usize>,
+
+    /// File name of the stored example without the extension, also its key in the answers
+    #[arg(long, default_value = "example")]
+    pub name: String,
+
+    /// Expected answer of part 1, instead of the one guessed from the page
+    #[arg(long, value_parser = Answer::from_str)]
+    pub part1: Option<Answer>,
+
+    /// Expected answer of part 2, instead of the one guessed from the page
+    #[arg(long, value_parser = Answer::from_str)]
+    pub part2: Option<Answer>,
+
+    /// Directory containing the example inputs
+    #[arg(long, default_value = "examples")]
+    pub examples: String,
+
+    /// File with the known correct answers
+    #[arg(long, default_value = "answers.toml")]
+    pub answers: String,
+
+    #[command(flatten)]
+    pub remote: RemoteArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct LeaderboardArgs {
+    /// Id of the private leaderboard to download, instead of `leaderboard` in the config
+    #[arg(long, conflicts_with = "file")]
+    pub id: Option<String>,
+
+    /// Leaderboard JSON file to read instead of downloading it
+    #[arg(long)]
+    pub file: Option<String>,
+
+    /// Directory caching downloaded leaderboards
+    #[arg(long, default_value = ".cache")]
+    pub cache: String,
+
+    /// Output format of the standings
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    #[command(flatten)]
+    pub remote: RemoteArgs,
+}
+
+/// How to reach the Advent of Code website, shared by the commands talking to it.
+#[derive(clap::Args, Debug, Clone)]
+pub struct RemoteArgs {
+    /// Config file with the session token, see the README
+    #[arg(long, default_value = "aoc.toml")]
+    pub config: String,
+
+    /// Base URL of the website, overrides `AOC_BASE_URL` and the config file
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+/// Where to read the puzzle input from, shared by every binary that solves puzzles.
+#[derive(clap::Args, Debug, Clone)]
+pub struct InputArgs {
+    /// Input file of the day, `-` reads from stdin. Defaults to `<INPUTS>/dayN.txt`
+    #[arg(short, long)]
+    pub input: Option<String>,
+
+    /// Use the Nth stored example of the day, see `<EXAMPLES>/dayN/`
+    #[arg(short, long, conflicts_with = "input")]
+    pub example: Option<usize>,
+
+    /// Directory containing the puzzle inputs
+    #[arg(long, default_value = "inputs")]
+    pub inputs: String,
+
+    /// Directory containing the example inputs
+    #[arg(long, default_value = "examples")]
+    pub examples: String,
+}
@@ -0,0 +1,291 @@
+use crate::{Direction, Grid, Point};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A fixed size set of the integers `0..capacity`, one bit each.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    capacity: usize,
+    len: usize,
+}
+
+impl BitSet {
+    pub fn new(capacity: usize) -> Self {
+        BitSet {
+            words: vec![0; capacity.div_ceil(WORD_BITS)],
+            capacity,
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Add `i`, returning whether it was not in the set yet. Panics if `i` is out of range.
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(
+            i < self.capacity,
+            "{} is outside of the bit set of {}",
+            i,
+            self.capacity
+        );
+        let (word, mask) = (i / WORD_BITS, 1 << (i % WORD_BITS));
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        self.len += added as usize;
+        added
+    }
+
+    /// Remove `i`, returning whether it was in the set.
+    pub fn remove(&mut self, i: usize) -> bool {
+        if !self.contains(i) {
+            return false;
+        }
+        self.words[i / WORD_BITS] &= !(1 << (i % WORD_BITS));
+        self.len -= 1;
+        true
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        i < self.capacity && self.words[i / WORD_BITS] & (1 << (i % WORD_BITS)) != 0
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+
+    /// The integers in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(w * WORD_BITS + bit)
+            })
+        })
+    }
+}
+
+/// A set of positions on a grid of a fixed size, for example the visited cells of a search.
+///
+/// Positions outside of the grid are never contained, inserting one panics.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    bits: BitSet,
+    rows: usize,
+    cols: usize,
+}
+
+impl BitGrid {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        BitGrid {
+            bits: BitSet::new(rows * cols),
+            rows,
+            cols,
+        }
+    }
+
+    /// Empty set for the positions of `grid`.
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        BitGrid::new(grid.rows(), grid.cols())
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        (0 <= pos.row
+            && 0 <= pos.col
+            && (pos.row as usize) < self.rows
+            && (pos.col as usize) < self.cols)
+            .then(|| pos.row as usize * self.cols + pos.col as usize)
+    }
+
+    fn expect_index(&self, pos: Point) -> usize {
+        self.index_of(pos).unwrap_or_else(|| {
+            panic!(
+                "position {} is outside of the {}x{} grid",
+                pos, self.rows, self.cols
+            )
+        })
+    }
+
+    /// Add `pos`, returning whether it was not in the set yet.
+    pub fn insert(&mut self, pos: Point) -> bool {
+        let i = self.expect_index(pos);
+        self.bits.insert(i)
+    }
+
+    pub fn remove(&mut self, pos: Point) -> bool {
+        self.index_of(pos).is_some_and(|i| self.bits.remove(i))
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.index_of(pos).is_some_and(|i| self.bits.contains(i))
+    }
+
+    /// Number of positions in the set.
+    pub fn count(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// The positions in the set in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        self.bits
+            .iter()
+            .map(|i| Point::from_index(i / self.cols, i % self.cols))
+    }
+}
+
+/// A set of `(position, direction)` pairs on a grid of a fixed size, for example the states
+/// of a walk which has to detect when it starts repeating itself.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DirectionBitGrid {
+    cells: BitGrid,
+    bits: BitSet,
+}
+
+impl DirectionBitGrid {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        DirectionBitGrid {
+            cells: BitGrid::new(rows, cols),
+            bits: BitSet::new(rows * cols * Direction::ALL.len()),
+        }
+    }
+
+    /// Empty set for the positions of `grid`.
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        DirectionBitGrid::new(grid.rows(), grid.cols())
+    }
+
+    /// Add `pos` facing `direction`, returning whether the pair was not in the set yet.
+    pub fn insert(&mut self, pos: Point, direction: Direction) -> bool {
+        let i = self.cells.expect_index(pos);
+        self.cells.bits.insert(i);
+        self.bits
+            .insert(i * Direction::ALL.len() + direction as usize)
+    }
+
+    pub fn contains(&self, pos: Point, direction: Direction) -> bool {
+        self.cells.index_of(pos).is_some_and(|i| {
+            self.bits
+                .contains(i * Direction::ALL.len() + direction as usize)
+        })
+    }
+
+    /// The directions `pos` was inserted with.
+    pub fn directions(&self, pos: Point) -> impl Iterator<Item = Direction> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter(move |&direction| self.contains(pos, direction))
+    }
+
+    /// The positions inserted with any direction.
+    pub fn positions(&self) -> &BitGrid {
+        &self.cells
+    }
+
+    /// Number of `(position, direction)` pairs in the set.
+    pub fn count(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bits.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_set_word_boundaries() {
+        let mut set = BitSet::new(130);
+        for i in [0, 63, 64, 127, 128, 129] {
+            assert!(set.insert(i));
+        }
+        assert!(!set.insert(64));
+        assert_eq!(set.len(), 6);
+        assert!(set.contains(63) && set.contains(64) && !set.contains(62) && !set.contains(65));
+        assert!(!set.contains(130) && !set.contains(1000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 64, 127, 128, 129]);
+        assert!(set.remove(63) && !set.remove(63) && !set.remove(500));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 64, 127, 128, 129]);
+        set.clear();
+        assert!(set.is_empty() && set.iter().next().is_none());
+
+        let exact = BitSet::new(64);
+        assert_eq!(exact.words.len(), 1);
+        assert!(!exact.contains(64));
+        assert_eq!(BitSet::new(0).iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "64 is outside of the bit set of 64")]
+    fn bit_set_insert_out_of_range() {
+        BitSet::new(64).insert(64);
+    }
+
+    #[test]
+    fn bit_grid_positions() {
+        let mut grid = BitGrid::new(3, 4);
+        assert!(grid.insert(Point::new(2, 3)));
+        assert!(grid.insert(Point::new(0, 1)));
+        assert!(!grid.insert(Point::new(0, 1)));
+        assert_eq!(grid.count(), 2);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(2, 3)]
+        );
+        // positions outside the grid never alias cells inside it
+        assert!(!grid.contains(Point::new(1, -1)) && !grid.contains(Point::new(0, 5)));
+        assert!(!grid.remove(Point::new(-1, 0)));
+        assert!(grid.remove(Point::new(2, 3)) && grid.count() == 1);
+    }
+
+    #[test]
+    fn direction_bit_grid_states() {
+        let mut states = DirectionBitGrid::new(2, 2);
+        let pos = Point::new(1, 0);
+        assert!(states.insert(pos, Direction::Up));
+        assert!(states.insert(pos, Direction::Left));
+        assert!(!states.insert(pos, Direction::Up));
+        assert!(states.insert(Point::new(1, 1), Direction::Up));
+        assert!(states.contains(pos, Direction::Left) && !states.contains(pos, Direction::Down));
+        assert_eq!(
+            states.directions(pos).collect::<Vec<_>>(),
+            [Direction::Up, Direction::Left]
+        );
+        assert_eq!((states.count(), states.positions().count()), (3, 2));
+        assert!(!states.contains(Point::new(2, 0), Direction::Up));
+        states.clear();
+        assert!(states.is_empty() && states.positions().is_empty());
+    }
+}
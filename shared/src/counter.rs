@@ -0,0 +1,257 @@
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+use std::ops::{Add, BitAnd, BitOr, Sub};
+
+/// A multiset counting how many times each item was added.
+///
+/// Items with a count of zero are never stored, so `len` is the number of distinct items.
+/// The operators work on references like those of `HashSet`: `&a + &b` adds the counts,
+/// `&a - &b` subtracts them (dropping items that reach zero), `&a | &b` keeps the maximum
+/// and `&a & &b` the minimum of both counts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counter<T: Hash + Eq> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    pub fn new() -> Self {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    /// Add `item` `n` times.
+    pub fn add_n(&mut self, item: T, n: usize) {
+        if n > 0 {
+            *self.counts.entry(item).or_insert(0) += n;
+        }
+    }
+
+    /// Remove one occurrence of `item`, if there is any.
+    pub fn remove(&mut self, item: &T) {
+        self.remove_n(item, 1);
+    }
+
+    /// Remove up to `n` occurrences of `item`.
+    pub fn remove_n(&mut self, item: &T, n: usize) {
+        if let Some(count) = self.counts.get_mut(item) {
+            if *count > n {
+                *count -= n;
+            } else {
+                self.counts.remove(item);
+            }
+        }
+    }
+
+    pub fn get(&self, item: &T) -> usize {
+        *self.counts.get(item).unwrap_or(&0)
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.counts.contains_key(item)
+    }
+
+    /// Number of distinct items.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Sum of all counts.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Distinct items with their counts, in arbitrary order.
+    pub fn iter(&self) -> CounterIter<'_, T> {
+        CounterIter {
+            inner: self.counts.iter(),
+        }
+    }
+
+    /// The `n` items with the highest counts, most common first. Ties are ordered by item.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)>
+    where
+        T: Ord,
+    {
+        let mut items: Vec<(&T, usize)> = self.iter().collect();
+        items.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        items.truncate(n);
+        items
+    }
+
+    /// Combine the counts of both counters with `f`, dropping items whose result is zero.
+    fn combine<F>(&self, other: &Counter<T>, f: F) -> Counter<T>
+    where
+        T: Clone,
+        F: Fn(usize, usize) -> usize,
+    {
+        let mut result = Counter::new();
+        for (item, count) in self.iter() {
+            result.add_n(item.clone(), f(count, other.get(item)));
+        }
+        for (item, count) in other.iter().filter(|(item, _)| !self.contains(item)) {
+            result.add_n(item.clone(), f(0, count));
+        }
+        result
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<(T, usize)> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = (T, usize)>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Hash + Eq> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Hash + Eq> Extend<(T, usize)> for Counter<T> {
+    fn extend<I: IntoIterator<Item = (T, usize)>>(&mut self, iter: I) {
+        for (item, n) in iter {
+            self.add_n(item, n);
+        }
+    }
+}
+
+/// Iterator over the items of a `Counter` with their counts, see `Counter::iter`.
+pub struct CounterIter<'a, T> {
+    inner: hash_map::Iter<'a, T, usize>,
+}
+
+impl<'a, T> Iterator for CounterIter<'a, T> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(item, &count)| (item, count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: Hash + Eq> IntoIterator for &'a Counter<T> {
+    type Item = (&'a T, usize);
+    type IntoIter = CounterIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Hash + Eq> IntoIterator for Counter<T> {
+    type Item = (T, usize);
+    type IntoIter = hash_map::IntoIter<T, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+impl<T: Hash + Eq + Clone> Add for &Counter<T> {
+    type Output = Counter<T>;
+
+    fn add(self, rhs: &Counter<T>) -> Counter<T> {
+        self.combine(rhs, |a, b| a + b)
+    }
+}
+
+impl<T: Hash + Eq + Clone> Sub for &Counter<T> {
+    type Output = Counter<T>;
+
+    fn sub(self, rhs: &Counter<T>) -> Counter<T> {
+        self.combine(rhs, usize::saturating_sub)
+    }
+}
+
+impl<T: Hash + Eq + Clone> BitOr for &Counter<T> {
+    type Output = Counter<T>;
+
+    fn bitor(self, rhs: &Counter<T>) -> Counter<T> {
+        self.combine(rhs, usize::max)
+    }
+}
+
+impl<T: Hash + Eq + Clone> BitAnd for &Counter<T> {
+    type Output = Counter<T>;
+
+    fn bitand(self, rhs: &Counter<T>) -> Counter<T> {
+        self.combine(rhs, usize::min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter(items: &str) -> Counter<char> {
+        items.chars().collect()
+    }
+
+    #[test]
+    fn adding_and_removing() {
+        let mut c = counter("abca");
+        assert_eq!((c.get(&'a'), c.get(&'z'), c.len(), c.total()), (2, 0, 3, 4));
+        c.add_n('z', 0);
+        assert!(!c.contains(&'z'));
+        c.remove(&'a');
+        c.remove_n(&'b', 5);
+        c.remove(&'z');
+        assert_eq!(c, counter("ac"));
+        c.extend([('c', 2)]);
+        assert_eq!(c.get(&'c'), 3);
+        c.remove_n(&'c', 3);
+        assert_eq!(c, counter("a"));
+    }
+
+    #[test]
+    fn operators() {
+        let (a, b) = (counter("aaab"), counter("abbc"));
+        assert_eq!(&a + &b, counter("aaaabbbc"));
+        // counts stop at zero and such items are dropped
+        assert_eq!(&a - &b, counter("aa"));
+        assert_eq!(&b - &a, counter("bc"));
+        assert!(!(&a - &b).contains(&'b'));
+        assert_eq!(&a | &b, counter("aaabbc"));
+        assert_eq!(&a & &b, counter("ab"));
+        assert_eq!(&a & &Counter::new(), Counter::new());
+        assert_eq!((&a & &b).len(), 2);
+    }
+
+    #[test]
+    fn most_common_breaks_ties_by_item() {
+        let c = counter("cbbaadd");
+        assert_eq!(c.most_common(3), vec![(&'a', 2), (&'b', 2), (&'d', 2)]);
+        assert_eq!(c.most_common(10).last(), Some(&(&'c', 1)));
+        assert_eq!(counter("").most_common(2), vec![]);
+    }
+}
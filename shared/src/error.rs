@@ -0,0 +1,123 @@
+use std::fmt;
+use std::str::FromStr;
+
+pub type AocResult<T> = Result<T, AocError>;
+
+/// Error raised while reading or parsing a puzzle input.
+///
+/// `line` and `column` are 1-based and point at the offending token when known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AocError {
+    pub message: String,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError {
+            message: message.into(),
+            file: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn at_line(line: usize, message: impl Into<String>) -> Self {
+        AocError {
+            line: Some(line),
+            ..AocError::new(message)
+        }
+    }
+
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError {
+            line: Some(line),
+            column: Some(column),
+            ..AocError::new(message)
+        }
+    }
+
+    /// Error of the file operation `action`, like `read` or `write`, on `file`.
+    pub fn io(action: &str, file: &str, err: std::io::Error) -> Self {
+        AocError::new(format!("failed to {}: {}", action, err)).with_file(file)
+    }
+
+    /// Error of a TOML document `text` which failed to deserialize, pointing at the span.
+    pub fn from_toml(text: &str, err: &toml::de::Error) -> Self {
+        let mut error = AocError::new(err.message());
+        if let Some(span) = err.span() {
+            let before = &text[..span.start];
+            error.line = Some(before.matches('\n').count() + 1);
+            error.column = Some(before.chars().rev().take_while(|&c| c != '\n').count() + 1);
+        }
+        error
+    }
+
+    /// Attach the file the error originates from, unless one is set already.
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        if self.file.is_none() {
+            self.file = Some(file.into());
+        }
+        self
+    }
+
+    /// Render the error like a compiler diagnostic, quoting the offending line of `source`.
+    pub fn render(&self, source: Option<&str>) -> String {
+        let mut out = format!("error: {}", self);
+        let Some(line_no) = self.line else {
+            return out;
+        };
+        let Some(line) = source.and_then(|source| source.lines().nth(line_no - 1)) else {
+            return out;
+        };
+        let gutter = " ".repeat(line_no.to_string().len());
+        out.push_str(&format!("\n{} |\n{} | {}", gutter, line_no, line));
+        if let Some(column) = self.column {
+            out.push_str(&format!("\n{} | {}^", gutter, " ".repeat(column - 1)));
+        }
+        out
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [
+            self.file.clone(),
+            self.line.map(|line| line.to_string()),
+            self.column.map(|column| column.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(":"))?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for AocError {}
+
+/// 1-based column of `token` within `line`. `token` must be a subslice of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    let offset = offset.min(line.len());
+    line[..offset].chars().count() + 1
+}
+
+/// Parse `token`, a subslice of line number `line_no` (1-based), reporting its position on failure.
+pub fn parse_token<T>(line_no: usize, line: &str, token: &str) -> AocResult<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    token.parse::<T>().map_err(|err| {
+        AocError::at(
+            line_no,
+            column_of(line, token),
+            format!("cannot parse '{}': {}", token, err),
+        )
+    })
+}
@@ -0,0 +1,48 @@
+use crate::{Answer, ExpectedAnswers, InputSource, Part, Solution};
+use std::path::Path;
+
+/// Run `part` of `S` on the stored example `name` of the day, panicking unless it gives the
+/// expected answer from `answers.toml`.
+///
+/// `manifest_dir` is the directory of the day's crate; examples and answers are looked
+/// up relative to the workspace root above it. Used by the tests of `example_tests!`.
+pub fn check_example<S: Solution>(manifest_dir: &str, name: &str, part: Part) {
+    let root = Path::new(manifest_dir)
+        .parent()
+        .expect("day crates live in the workspace root");
+    let path = root
+        .join("examples")
+        .join(format!("day{}", S::DAY))
+        .join(format!("{}.txt", name));
+    let answers_path = root.join("answers.toml").display().to_string();
+    let expected = ExpectedAnswers::load(&answers_path).unwrap_or_else(|err| panic!("{}", err));
+    let Some(expected) = expected.get(S::DAY, name, part) else {
+        panic!(
+            "{}: no answer for part {} in {}",
+            path.display(),
+            part,
+            answers_path
+        );
+    };
+
+    let text = InputSource::File(path.clone())
+        .read()
+        .unwrap_or_else(|err| panic!("{}", err))
+        .text;
+    let input = S::parse(&text).unwrap_or_else(|err| {
+        let err = err.with_file(path.display().to_string());
+        panic!("\n{}", err.render(Some(&text)))
+    });
+    let answer: Answer = match part {
+        Part::One => S::part1(&input).into(),
+        Part::Two => S::part2(&input).into(),
+    };
+    assert!(
+        &answer == expected,
+        "{}: part {} answered {}, expected {}",
+        path.display(),
+        part,
+        answer,
+        expected
+    );
+}
@@ -0,0 +1,587 @@
+use crate::{Grid, Point};
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Add;
+
+// The searches take the graph as a `successors` function, so they work on anything from an
+// explicit `Graph` to an implicit state space. `Grid::moves4` adapts grids to them.
+
+const NO_PARENT: usize = usize::MAX;
+
+/// Walk the `parents` links back from node `i` and return the path leading to it.
+fn path_to<N: Clone>(nodes: &[N], parents: &[usize], mut i: usize) -> Vec<N> {
+    let mut path = vec![nodes[i].clone()];
+    while parents[i] != NO_PARENT {
+        i = parents[i];
+        path.push(nodes[i].clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Shortest path in number of steps from `start` to the first node satisfying `is_goal`.
+/// The path includes both ends.
+pub fn bfs<N, FS, IS, FG>(start: N, mut successors: FS, mut is_goal: FG) -> Option<Vec<N>>
+where
+    N: Clone + Hash + Eq,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![NO_PARENT];
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&nodes[i]) {
+            return Some(path_to(&nodes, &parents, i));
+        }
+        for next in successors(&nodes[i]) {
+            if seen.insert(next.clone()) {
+                queue.push_back(nodes.len());
+                nodes.push(next);
+                parents.push(i);
+            }
+        }
+    }
+    None
+}
+
+/// Number of steps from `start` to every node reachable from it.
+pub fn bfs_distances<N, FS, IS>(start: N, mut successors: FS) -> HashMap<N, usize>
+where
+    N: Clone + Hash + Eq,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Nodes reachable from `start` in depth-first preorder, `start` first.
+pub fn dfs<N, FS, IS>(start: N, mut successors: FS) -> Vec<N>
+where
+    N: Clone + Hash + Eq,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+{
+    let mut order = Vec::new();
+    let mut seen = HashSet::new();
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        // push in reverse so the first successor is visited first
+        let next: Vec<N> = successors(&node).into_iter().collect();
+        stack.extend(next.into_iter().rev().filter(|next| !seen.contains(next)));
+        order.push(node);
+    }
+    order
+}
+
+/// Cheapest path from `start` to the first node satisfying `is_goal` and its cost.
+/// `successors` yields the neighbors of a node with the cost of moving there.
+pub fn dijkstra<N, C, FS, IS, FG>(start: N, successors: FS, is_goal: FG) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Hash + Eq,
+    C: Copy + Ord + Add<Output = C> + Default,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Cost of the cheapest path from `start` to every node reachable from it.
+pub fn dijkstra_all<N, C, FS, IS>(start: N, mut successors: FS) -> HashMap<N, C>
+where
+    N: Clone + Hash + Eq,
+    C: Copy + Ord + Add<Output = C> + Default,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = vec![start.clone()];
+    let mut costs = HashMap::from([(start, C::default())]);
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        if cost > costs[&nodes[i]] {
+            continue;
+        }
+        for (next, step) in successors(&nodes[i]) {
+            let next_cost = cost + step;
+            match costs.get(&next) {
+                Some(&known) if known <= next_cost => {}
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    heap.push(Reverse((next_cost, nodes.len())));
+                    nodes.push(next);
+                }
+            }
+        }
+    }
+    costs
+}
+
+/// Like `dijkstra`, but guided by `heuristic`, an estimate of the remaining cost to a goal
+/// which must never overestimate it.
+pub fn astar<N, C, FS, IS, FH, FG>(
+    start: N,
+    mut successors: FS,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Hash + Eq,
+    C: Copy + Ord + Add<Output = C> + Default,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![NO_PARENT];
+    let mut costs = vec![C::default()];
+    let mut index = HashMap::from([(start, 0)]);
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+        if is_goal(&nodes[i]) {
+            return Some((path_to(&nodes, &parents, i), cost));
+        }
+        for (next, step) in successors(&nodes[i]) {
+            let next_cost = cost + step;
+            let j = match index.entry(next) {
+                Entry::Vacant(entry) => {
+                    let j = nodes.len();
+                    nodes.push(entry.key().clone());
+                    parents.push(i);
+                    costs.push(next_cost);
+                    entry.insert(j);
+                    j
+                }
+                Entry::Occupied(entry) => {
+                    let j = *entry.get();
+                    if costs[j] <= next_cost {
+                        continue;
+                    }
+                    parents[j] = i;
+                    costs[j] = next_cost;
+                    j
+                }
+            };
+            heap.push(Reverse((next_cost + heuristic(&nodes[j]), next_cost, j)));
+        }
+    }
+    None
+}
+
+/// A cycle found by `Graph::toposort`, as a path starting and ending at the same node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<N> {
+    pub path: Vec<N>,
+}
+
+impl<N: fmt::Display> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path: Vec<String> = self.path.iter().map(|node| node.to_string()).collect();
+        write!(f, "cycle {}", path.join(" -> "))
+    }
+}
+
+/// A directed graph with nodes of type `N`.
+///
+/// Nodes are kept in insertion order, which makes the results of the algorithms below
+/// deterministic.
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<N: Clone + Hash + Eq> Default for Graph<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Clone + Hash + Eq> Graph<N> {
+    pub fn new() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Add `node` unless it is already in the graph, and return its index.
+    pub fn add_node(&mut self, node: N) -> usize {
+        match self.index.entry(node) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                let i = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                self.edges.push(Vec::new());
+                entry.insert(i);
+                i
+            }
+        }
+    }
+
+    /// Add an edge from `from` to `to`, adding the nodes as needed.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        if !self.edges[from].contains(&to) {
+            self.edges[from].push(to);
+        }
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    /// Nodes in insertion order.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Nodes with an edge from `node`, empty if `node` is not in the graph.
+    pub fn successors<'a>(&'a self, node: &N) -> impl Iterator<Item = &'a N> + 'a {
+        let targets = match self.index.get(node) {
+            Some(&i) => self.edges[i].as_slice(),
+            None => &[],
+        };
+        targets.iter().map(|&j| &self.nodes[j])
+    }
+
+    pub fn has_edge(&self, from: &N, to: &N) -> bool {
+        match (self.index.get(from), self.index.get(to)) {
+            (Some(&from), Some(&to)) => self.edges[from].contains(&to),
+            _ => false,
+        }
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.edges.iter().enumerate().flat_map(move |(i, targets)| {
+            targets
+                .iter()
+                .map(move |&j| (&self.nodes[i], &self.nodes[j]))
+        })
+    }
+
+    /// The graph restricted to the nodes for which `keep` holds.
+    pub fn subgraph<F>(&self, mut keep: F) -> Graph<N>
+    where
+        F: FnMut(&N) -> bool,
+    {
+        let mut graph = Graph::new();
+        for node in self.nodes.iter().filter(|node| keep(node)) {
+            graph.add_node(node.clone());
+        }
+        for (from, to) in self.edges() {
+            if graph.contains(from) && graph.contains(to) {
+                graph.add_edge(from.clone(), to.clone());
+            }
+        }
+        graph
+    }
+
+    /// Order the nodes such that every edge points forward, or return a cycle preventing it.
+    pub fn toposort(&self) -> Result<Vec<N>, Cycle<N>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            OnStack,
+            Done,
+        }
+        let mut state = vec![State::New; self.nodes.len()];
+        let mut postorder = Vec::with_capacity(self.nodes.len());
+        for root in 0..self.nodes.len() {
+            if state[root] != State::New {
+                continue;
+            }
+            // stack of nodes with the index of the next edge to follow
+            let mut stack = vec![(root, 0)];
+            state[root] = State::OnStack;
+            while let Some(&mut (i, ref mut next_edge)) = stack.last_mut() {
+                let Some(&j) = self.edges[i].get(*next_edge) else {
+                    state[i] = State::Done;
+                    postorder.push(i);
+                    stack.pop();
+                    continue;
+                };
+                *next_edge += 1;
+                match state[j] {
+                    State::New => {
+                        state[j] = State::OnStack;
+                        stack.push((j, 0));
+                    }
+                    State::OnStack => {
+                        let start = stack.iter().position(|&(k, _)| k == j).unwrap();
+                        let path = stack[start..]
+                            .iter()
+                            .map(|&(k, _)| self.nodes[k].clone())
+                            .chain(std::iter::once(self.nodes[j].clone()))
+                            .collect();
+                        return Err(Cycle { path });
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        Ok(postorder
+            .into_iter()
+            .rev()
+            .map(|i| self.nodes[i].clone())
+            .collect())
+    }
+
+    /// Groups of nodes connected to each other when ignoring the direction of the edges.
+    pub fn connected_components(&self) -> Vec<Vec<N>> {
+        let mut undirected = vec![Vec::new(); self.nodes.len()];
+        for (i, targets) in self.edges.iter().enumerate() {
+            for &j in targets {
+                undirected[i].push(j);
+                undirected[j].push(i);
+            }
+        }
+        let mut seen = vec![false; self.nodes.len()];
+        let mut components = Vec::new();
+        for root in 0..self.nodes.len() {
+            if seen[root] {
+                continue;
+            }
+            seen[root] = true;
+            let mut component = Vec::new();
+            let mut stack = vec![root];
+            while let Some(i) = stack.pop() {
+                component.push(self.nodes[i].clone());
+                for &j in &undirected[i] {
+                    if !seen[j] {
+                        seen[j] = true;
+                        stack.push(j);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// Strongly connected components using Tarjan's algorithm. Components come in reverse
+    /// topological order: no edge leads from a component to one listed after it.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        let n = self.nodes.len();
+        let mut order = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut counter = 0;
+        let mut components = Vec::new();
+        for root in 0..n {
+            if order[root] != usize::MAX {
+                continue;
+            }
+            let mut call_stack = vec![(root, 0)];
+            while let Some(&mut (i, ref mut next_edge)) = call_stack.last_mut() {
+                if *next_edge == 0 {
+                    order[i] = counter;
+                    low[i] = counter;
+                    counter += 1;
+                    stack.push(i);
+                    on_stack[i] = true;
+                }
+                if let Some(&j) = self.edges[i].get(*next_edge) {
+                    *next_edge += 1;
+                    if order[j] == usize::MAX {
+                        call_stack.push((j, 0));
+                    } else if on_stack[j] {
+                        low[i] = low[i].min(order[j]);
+                    }
+                    continue;
+                }
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    low[parent] = low[parent].min(low[i]);
+                }
+                if low[i] == order[i] {
+                    let mut component = Vec::new();
+                    while let Some(j) = stack.pop() {
+                        on_stack[j] = false;
+                        component.push(self.nodes[j].clone());
+                        if j == i {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+}
+
+impl<N: Clone + Hash + Eq> FromIterator<(N, N)> for Graph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(iter: I) -> Self {
+        let mut graph = Graph::new();
+        for (from, to) in iter {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+}
+
+impl<T> Grid<T> {
+    /// Successors for the searches above: the orthogonal neighbors of a position which
+    /// `passable(from, to)` allows to move to.
+    pub fn moves4<'a, F>(&'a self, passable: F) -> impl Fn(&Point) -> Vec<Point> + 'a
+    where
+        F: Fn(&T, &T) -> bool + 'a,
+    {
+        move |&pos| {
+            self.neighbors4(pos)
+                .filter(|&next| passable(&self[pos], &self[next]))
+                .collect()
+        }
+    }
+
+    /// Weighted successors for `dijkstra` and `astar`: the orthogonal neighbors of a position
+    /// with the cost of moving there, `None` if that is not possible.
+    pub fn weighted_moves4<'a, C, F>(&'a self, cost: F) -> impl Fn(&Point) -> Vec<(Point, C)> + 'a
+    where
+        F: Fn(&T, &T) -> Option<C> + 'a,
+    {
+        move |&pos| {
+            self.neighbors4(pos)
+                .filter_map(|next| Some((next, cost(&self[pos], &self[next])?)))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(u32, u32)]) -> Graph<u32> {
+        edges.iter().copied().collect()
+    }
+
+    #[test]
+    fn toposort_orders_or_finds_a_cycle() {
+        let mut dag = graph(&[(5, 3), (3, 1), (5, 1), (2, 3)]);
+        dag.add_node(7);
+        let order = dag.toposort().unwrap();
+        assert_eq!(order.len(), 5);
+        let position = |node| order.iter().position(|&n| n == node).unwrap();
+        for (from, to) in dag.edges() {
+            assert!(position(*from) < position(*to), "{:?}", order);
+        }
+
+        let cyclic = graph(&[(1, 2), (2, 3), (3, 4), (4, 2), (4, 5)]);
+        let cycle = cyclic.toposort().unwrap_err();
+        assert_eq!(cycle.path, vec![2, 3, 4, 2]);
+        assert_eq!(cycle.to_string(), "cycle 2 -> 3 -> 4 -> 2");
+        assert_eq!(graph(&[(1, 1)]).toposort().unwrap_err().path, vec![1, 1]);
+    }
+
+    #[test]
+    fn components() {
+        let mut g = graph(&[(1, 2), (3, 2), (4, 5)]);
+        g.add_node(6);
+        assert_eq!(
+            g.connected_components(),
+            vec![vec![1, 2, 3], vec![4, 5], vec![6]]
+        );
+
+        // components come after every component they have an edge to
+        let g = graph(&[(1, 2), (2, 1), (2, 3), (3, 4), (4, 3), (4, 5)]);
+        assert_eq!(
+            g.strongly_connected_components(),
+            vec![vec![5], vec![4, 3], vec![2, 1]]
+        );
+        let sub = g.subgraph(|&node| node != 2);
+        assert_eq!(sub.nodes(), &[1, 3, 4, 5]);
+        assert!(sub.has_edge(&3, &4) && !sub.has_edge(&1, &2));
+        assert_eq!(sub.strongly_connected_components().len(), 3);
+    }
+
+    #[test]
+    fn unweighted_searches() {
+        let g = graph(&[(1, 2), (1, 3), (2, 4), (3, 4), (4, 5), (6, 1)]);
+        let successors = |node: &u32| g.successors(node).copied().collect::<Vec<_>>();
+        assert_eq!(
+            bfs(1, successors, |&node| node == 5),
+            Some(vec![1, 2, 4, 5])
+        );
+        assert_eq!(bfs(1, successors, |&node| node == 6), None);
+        assert_eq!(dfs(1, successors), vec![1, 2, 4, 5, 3]);
+        let distances = bfs_distances(1, successors);
+        assert_eq!(distances.len(), 5);
+        assert_eq!((distances[&1], distances[&4], distances[&5]), (0, 2, 3));
+    }
+
+    #[test]
+    fn grid_searches() {
+        let maze = Grid::parse("S.#.\n..#.\n...E\n").unwrap();
+        let open = maze.moves4(|_, &to| to != '#');
+        let end = Point::new(2, 3);
+        let path = bfs(Point::new(0, 0), &open, |&pos| pos == end).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!(bfs_distances(Point::new(0, 0), &open).len(), 10);
+        let around = bfs(Point::new(0, 0), &open, |&pos| pos == Point::new(0, 3)).unwrap();
+        assert_eq!(around.len(), 8);
+        assert_eq!(around[5..], [end, Point::new(1, 3), Point::new(0, 3)]);
+        let walled = maze.moves4(|_, _| false);
+        assert_eq!(bfs(Point::new(0, 0), walled, |&pos| pos == end), None);
+
+        // entering a cell costs its digit
+        let costs = Grid::parse("131\n191\n111\n").unwrap();
+        let moves = costs.weighted_moves4(|_, to: &char| to.to_digit(10));
+        let (start, goal) = (Point::new(0, 0), Point::new(2, 2));
+        let cheapest = vec![
+            start,
+            Point::new(1, 0),
+            Point::new(2, 0),
+            Point::new(2, 1),
+            goal,
+        ];
+        assert_eq!(
+            dijkstra(start, &moves, |&pos| pos == goal),
+            Some((cheapest.clone(), 4))
+        );
+        let heuristic = |&pos: &Point| pos.manhattan_distance(goal) as u32;
+        assert_eq!(
+            astar(start, &moves, heuristic, |&pos| pos == goal),
+            Some((cheapest, 4))
+        );
+        let all = dijkstra_all(start, &moves);
+        assert_eq!(all.len(), 9);
+        assert_eq!((all[&goal], all[&Point::new(1, 1)]), (4, 10));
+        assert_eq!(dijkstra(start, &moves, |&pos| pos.row > 2), None);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::{AocError, AocResult, Dir8, Direction, Point, Vec2};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row in a single `Vec`.
+///
+/// Positions are `Point`s, row 0 being the first line of the input.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid with `rows` rows and `cols` columns, every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Grid from cells in row-major order. Panics if `cells` does not hold `rows * cols` cells.
+    pub fn from_vec(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), rows * cols, "grid cells do not match its size");
+        Grid { cells, rows, cols }
+    }
+
+    /// Parse a grid with one row per line, converting each character with `f`. All rows must
+    /// have the same length.
+    /// `f` gets the character and its zero-based `(line, column)`, errors of `f` are passed through.
+    pub fn parse_with<F>(input: &str, mut f: F) -> AocResult<Self>
+    where
+        F: FnMut(char, (usize, usize)) -> AocResult<T>,
+    {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+        for (i, line) in input.lines().enumerate() {
+            let mut ncols = 0;
+            for (j, c) in line.chars().enumerate() {
+                cells.push(f(c, (i, j))?);
+                ncols += 1;
+            }
+            if i == 0 {
+                cols = ncols;
+            } else if ncols != cols {
+                // point at the first extra character, or the end of a short row
+                return Err(AocError::at(
+                    i + 1,
+                    ncols.min(cols) + 1,
+                    format!(
+                        "grid is not rectangular: row has {} columns, expected {}",
+                        ncols, cols
+                    ),
+                ));
+            }
+            rows += 1;
+        }
+        if rows == 0 || cols == 0 {
+            return Err(AocError::new("input is empty, cannot construct a grid"));
+        }
+        Ok(Grid { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| pos.row as usize * self.cols + pos.col as usize)
+    }
+
+    pub fn in_bounds(&self, pos: Point) -> bool {
+        0 <= pos.row
+            && 0 <= pos.col
+            && (pos.row as usize) < self.rows
+            && (pos.col as usize) < self.cols
+    }
+
+    /// Cell at `pos`, `None` when it lies outside of the grid.
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Position `steps` times `delta` away from `pos`, if it is still inside the grid.
+    pub fn step(&self, pos: Point, delta: impl Into<Vec2>, steps: isize) -> Option<Point> {
+        let delta = delta.into();
+        let row = pos.row.checked_add(delta.row.checked_mul(steps)?)?;
+        let col = pos.col.checked_add(delta.col.checked_mul(steps)?)?;
+        let pos = Point::new(row, col);
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// Cell one `delta` away from `pos`, if it is still inside the grid.
+    pub fn get_offset(&self, pos: Point, delta: impl Into<Vec2>) -> Option<&T> {
+        self.step(pos, delta, 1).and_then(|pos| self.get(pos))
+    }
+
+    /// Positions of the grid in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let cols = self.cols;
+        (0..self.cells.len()).map(move |i| Point::from_index(i / cols, i % cols))
+    }
+
+    /// Cells with their positions, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Positions reached by stepping `delta` from `pos` until leaving the grid, `pos` excluded.
+    pub fn ray(&self, pos: Point, delta: impl Into<Vec2>) -> impl Iterator<Item = Point> + '_ {
+        let delta = delta.into();
+        (1..).map_while(move |steps| self.step(pos, delta, steps))
+    }
+
+    /// The up to four orthogonal neighbors of `pos` which are inside the grid.
+    pub fn neighbors4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction, 1))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbors of `pos` which are inside the grid.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction, 1))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.cols.max(1))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(move |col| self.column(col))
+    }
+
+    /// Diagonals running down and to the right, starting from the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (rows, cols) = self.nonempty_size();
+        let starts = (0..rows)
+            .rev()
+            .map(|row| Point::from_index(row, 0))
+            .chain((1..cols).map(|col| Point::from_index(0, col)));
+        starts.map(move |start| self.line(start, Dir8::DownRight))
+    }
+
+    /// Diagonals running down and to the left, starting from the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (rows, cols) = self.nonempty_size();
+        let last_col = cols.saturating_sub(1);
+        let starts = (0..cols)
+            .map(|col| Point::from_index(0, col))
+            .chain((1..rows).map(move |row| Point::from_index(row, last_col)));
+        starts.map(move |start| self.line(start, Dir8::DownLeft))
+    }
+
+    /// Size of the grid, or `(0, 0)` if it has no cells at all.
+    fn nonempty_size(&self) -> (usize, usize) {
+        if self.is_empty() {
+            (0, 0)
+        } else {
+            (self.rows, self.cols)
+        }
+    }
+
+    fn line(&self, start: Point, delta: Dir8) -> impl Iterator<Item = &T> {
+        std::iter::once(start)
+            .chain(self.ray(start, delta))
+            .map(move |pos| &self[pos])
+    }
+
+    /// Position of the first cell, in row-major order, for which `predicate` holds.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Position of the first cell equal to `value`.
+    pub fn position(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    /// Positions of all cells equal to `value`.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid of characters, one row per line.
+    pub fn parse(input: &str) -> AocResult<Self> {
+        Grid::parse_with(input, |c, _| Ok(c))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &Self::Output {
+        match self.index_of(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "position {} is outside of the {}x{} grid",
+                pos, self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        match self.index_of(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "position {} is outside of the {}x{} grid",
+                pos, self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn rows_columns_and_diagonals() {
+        let grid = Grid::parse("abc\ndef\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols(), grid.len()), (2, 3, 6));
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(lines(grid.iter_columns()), ["ad", "be", "cf"]);
+        assert_eq!(lines(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(lines(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+
+        let tall = Grid::parse("ab\ncd\nef\n").unwrap();
+        assert_eq!(lines(tall.diagonals()), ["e", "cf", "ad", "b"]);
+        assert_eq!(lines(tall.anti_diagonals()), ["a", "bc", "de", "f"]);
+
+        let single = Grid::parse("x").unwrap();
+        assert_eq!(lines(single.diagonals()), ["x"]);
+        assert_eq!(lines(single.anti_diagonals()), ["x"]);
+    }
+
+    #[test]
+    fn bounds_and_neighbors() {
+        let grid = Grid::parse("abc\ndef\n").unwrap();
+        let corner = Point::new(0, 0);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(1, 3)), None);
+        assert_eq!(grid.get_offset(corner, Direction::Right), Some(&'b'));
+        assert_eq!(grid.step(corner, Vec2::new(1, 1), 2), None);
+        assert_eq!(grid.ray(corner, Direction::Right).count(), 2);
+        assert_eq!(grid.neighbors4(corner).count(), 2);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 5);
+        assert_eq!(grid.position(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_cell() {
+        let long = Grid::parse("abc\nabcd\n").unwrap_err();
+        assert_eq!((long.line, long.column), (Some(2), Some(4)));
+        let short = Grid::parse("abc\nab\nabc\n").unwrap_err();
+        assert_eq!((short.line, short.column), (Some(2), Some(3)));
+        assert_eq!(
+            short.message,
+            "grid is not rectangular: row has 2 columns, expected 3"
+        );
+        assert!(Grid::parse("").is_err());
+        assert!(Grid::parse("\n\n").is_err());
+
+        let digits = Grid::parse_with("12\n3x\n", |c, (i, j)| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::at(i + 1, j + 1, "not a digit"))
+        });
+        let err = digits.unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{AocError, AocResult, InputArgs};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+/// Puzzle input text together with a name for it to use in diagnostics.
+#[derive(Clone, Debug)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    /// Key of the input in `answers.toml`: `input` for the puzzle input of the day and the
+    /// file stem for examples and other files. Inputs from stdin have no key.
+    pub key: Option<String>,
+}
+
+impl InputSource {
+    pub fn name(&self) -> String {
+        match self {
+            InputSource::Stdin => "<stdin>".to_string(),
+            InputSource::File(path) => path.display().to_string(),
+        }
+    }
+
+    pub fn read(&self) -> AocResult<Input> {
+        let name = self.name();
+        let text = match self {
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map(|_| text)
+                    .map_err(|err| AocError::io("read input", &name, err))?
+            }
+            InputSource::File(path) => {
+                fs::read_to_string(path).map_err(|err| AocError::io("read input", &name, err))?
+            }
+        };
+        let text = normalize_input(&text);
+        let key = match self {
+            InputSource::Stdin => None,
+            InputSource::File(path) => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned()),
+        };
+        Ok(Input { name, text, key })
+    }
+}
+
+/// Normalize puzzle input text so solutions only ever see one format: a leading byte order
+/// mark is dropped, CRLF and lone CR line endings become LF, and trailing blank lines are
+/// removed. Non-empty text ends with exactly one newline.
+pub fn normalize_input(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut lines: Vec<&str> = text.lines().collect();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        String::new()
+    } else {
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Key of the actual puzzle input of a day in `answers.toml`.
+pub const PUZZLE_INPUT_KEY: &str = "input";
+
+/// Default location of the puzzle input of `day`.
+pub fn default_input_path(inputs_dir: &str, day: u32) -> PathBuf {
+    Path::new(inputs_dir).join(format!("day{}.txt", day))
+}
+
+/// Example input files stored for `day`, sorted by file name.
+pub fn example_paths(examples_dir: &str, day: u32) -> AocResult<Vec<PathBuf>> {
+    let dir = Path::new(examples_dir).join(format!("day{}", day));
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(AocError::io(
+                "read directory",
+                &dir.display().to_string(),
+                err,
+            ))
+        }
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+impl InputArgs {
+    /// Resolve where the input of `day` comes from: an explicit `--input` (`-` for stdin),
+    /// the Nth stored example with `--example N`, or the default path in the inputs directory.
+    pub fn source(&self, day: u32) -> AocResult<InputSource> {
+        if let Some(n) = self.example {
+            let examples = example_paths(&self.examples, day)?;
+            return match n.checked_sub(1).and_then(|i| examples.get(i)) {
+                Some(path) => Ok(InputSource::File(path.clone())),
+                None => Err(AocError::new(format!(
+                    "day {} has {} stored examples in {}, cannot use example {}",
+                    day,
+                    examples.len(),
+                    self.examples,
+                    n
+                ))),
+            };
+        }
+        match self.input.as_deref() {
+            Some("-") => Ok(InputSource::Stdin),
+            Some(path) => Ok(InputSource::File(PathBuf::from(path))),
+            None => Ok(InputSource::File(default_input_path(&self.inputs, day))),
+        }
+    }
+
+    /// Read the input of `day`. The puzzle input gets the key `input` however its path is
+    /// spelled, like `./inputs/day6.txt` or an absolute path.
+    pub fn read(&self, day: u32) -> AocResult<Input> {
+        let source = self.source(day)?;
+        let mut input = source.read()?;
+        if let InputSource::File(path) = &source {
+            if same_file(path, &default_input_path(&self.inputs, day)) {
+                input.key = Some(PUZZLE_INPUT_KEY.to_string());
+            }
+        }
+        Ok(input)
+    }
+}
+
+/// Whether `a` and `b` are paths of the same existing file.
+fn same_file(a: &Path, b: &Path) -> bool {
+    a == b || matches!((fs::canonicalize(a), fs::canonicalize(b)), (Ok(a), Ok(b)) if a == b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings_bom_and_trailing_lines() {
+        assert_eq!(normalize_input("a\nb\n"), "a\nb\n");
+        assert_eq!(normalize_input("a\nb"), "a\nb\n");
+        assert_eq!(normalize_input("\u{feff}a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize_input("a\rb\r\rc"), "a\nb\n\nc\n");
+        assert_eq!(normalize_input("a\r\n\r\nb\n\n \n\t\r\n"), "a\n\nb\n");
+        // only a leading byte order mark is dropped, and leading blank lines are kept
+        assert_eq!(normalize_input("\na\u{feff}\n"), "\na\u{feff}\n");
+        assert_eq!(normalize_input(""), "");
+        assert_eq!(normalize_input("\u{feff}\r\n\n"), "");
+    }
+
+    #[test]
+    fn input_keys() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-keys-{}", std::process::id()));
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::create_dir_all(dir.join("examples/day6")).unwrap();
+        fs::write(dir.join("inputs/day6.txt"), "real\r\n").unwrap();
+        fs::write(dir.join("examples/day6/example.txt"), "example\n").unwrap();
+        fs::write(dir.join("other.txt"), "other\n").unwrap();
+        let path = |path: &str| dir.join(path).display().to_string();
+        let args = |input: Option<String>, example: Option<usize>| InputArgs {
+            input,
+            example,
+            inputs: path("inputs"),
+            examples: path("examples"),
+        };
+        let key = |args: InputArgs| args.read(6).unwrap().key;
+
+        let default = args(None, None).read(6).unwrap();
+        assert_eq!(default.text, "real\n");
+        assert_eq!(default.key.as_deref(), Some(PUZZLE_INPUT_KEY));
+        let spelled_differently = Some(path("inputs/../inputs/./day6.txt"));
+        assert_eq!(
+            key(args(spelled_differently, None)).as_deref(),
+            Some("input")
+        );
+        assert_eq!(
+            key(args(Some(path("other.txt")), None)).as_deref(),
+            Some("other")
+        );
+        assert_eq!(key(args(None, Some(1))).as_deref(), Some("example"));
+        assert!(args(None, Some(2)).read(6).is_err());
+        assert_eq!(
+            args(Some("-".to_string()), None).source(6).unwrap(),
+            InputSource::Stdin
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,195 @@
+use crate::math::Int;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// A set of values stored as disjoint half-open ranges.
+///
+/// Inserted ranges which overlap or touch are merged, so iterating yields the fewest
+/// ranges covering the set, in ascending order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// Start of each range mapped to its end.
+    ranges: BTreeMap<T, T>,
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            ranges: BTreeMap::new(),
+        }
+    }
+
+    /// Add all values of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = (range.start, range.end);
+        // ranges are disjoint, so their ends descend along with their starts
+        let merged: Vec<(T, T)> = self
+            .ranges
+            .range(..=end)
+            .rev()
+            .take_while(|(_, &e)| e >= start)
+            .map(|(&s, &e)| (s, e))
+            .collect();
+        for (s, e) in merged {
+            self.ranges.remove(&s);
+            start = start.min(s);
+            end = end.max(e);
+        }
+        self.ranges.insert(start, end);
+    }
+
+    /// Remove all values of `range`, splitting the ranges it cuts through.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let overlapping: Vec<(T, T)> = self
+            .ranges
+            .range(..range.end)
+            .rev()
+            .take_while(|(_, &e)| e > range.start)
+            .map(|(&s, &e)| (s, e))
+            .collect();
+        for (s, e) in overlapping {
+            self.ranges.remove(&s);
+            if s < range.start {
+                self.ranges.insert(s, range.start);
+            }
+            if range.end < e {
+                self.ranges.insert(range.end, e);
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &end)| value < end)
+    }
+
+    /// Whether every value of `range` is in the set. Empty ranges are always covered.
+    pub fn covers(&self, range: Range<T>) -> bool {
+        range.is_empty()
+            || self
+                .ranges
+                .range(..=range.start)
+                .next_back()
+                .is_some_and(|(_, &end)| range.end <= end)
+    }
+
+    /// Whether any value of `range` is in the set.
+    pub fn intersects(&self, range: Range<T>) -> bool {
+        !range.is_empty()
+            && self
+                .ranges
+                .range(..range.end)
+                .next_back()
+                .is_some_and(|(_, &end)| range.start < end)
+    }
+
+    /// Merge all ranges of `other` into this set.
+    pub fn union(&mut self, other: &IntervalSet<T>) {
+        self.extend(other.iter());
+    }
+
+    /// The disjoint ranges of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().map(|(&start, &end)| start..end)
+    }
+
+    /// Number of disjoint ranges, see `covered_len` for the number of values.
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Total length of all ranges.
+    pub fn covered_len(&self) -> T
+    where
+        T: Int,
+    {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |total, (&start, &end)| total + (end - start))
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ord + Copy> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|range| (range.start, range.end)).collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_touching_ranges() {
+        let mut set: IntervalSet<i32> = [10..20, 30..40, 50..60].into_iter().collect();
+        set.insert(5..5);
+        assert_eq!(ranges(&set), [(10, 20), (30, 40), (50, 60)]);
+        set.insert(20..25);
+        assert_eq!(ranges(&set), [(10, 25), (30, 40), (50, 60)]);
+        set.insert(33..36);
+        assert_eq!(ranges(&set), [(10, 25), (30, 40), (50, 60)]);
+        set.insert(24..55);
+        assert_eq!(ranges(&set), [(10, 60)]);
+        set.insert(-5..0);
+        set.insert(0..1);
+        assert_eq!(ranges(&set), [(-5, 1), (10, 60)]);
+        assert_eq!((set.range_count(), set.covered_len()), (2, 56));
+
+        let mut other = IntervalSet::new();
+        other.insert(1..10);
+        other.union(&set);
+        assert_eq!(ranges(&other), [(-5, 60)]);
+    }
+
+    #[test]
+    fn remove_splits_ranges() {
+        let mut set: IntervalSet<i32> = [0..10, 20..30].into_iter().collect();
+        set.remove(3..5);
+        assert_eq!(ranges(&set), [(0, 3), (5, 10), (20, 30)]);
+        set.remove(8..22);
+        assert_eq!(ranges(&set), [(0, 3), (5, 8), (22, 30)]);
+        set.remove(10..20);
+        set.remove(4..4);
+        assert_eq!(ranges(&set), [(0, 3), (5, 8), (22, 30)]);
+        set.remove(22..23);
+        set.remove(29..40);
+        assert_eq!(ranges(&set), [(0, 3), (5, 8), (23, 29)]);
+        set.remove(-10..100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn queries_at_the_range_ends() {
+        let set: IntervalSet<i32> = [0..10, 20..30].into_iter().collect();
+        assert!(set.contains(0) && set.contains(9) && !set.contains(10) && !set.contains(-1));
+        assert!(set.covers(20..30) && set.covers(5..5) && !set.covers(5..21));
+        assert!(set.intersects(9..20) && !set.intersects(10..20) && !set.intersects(25..25));
+        assert!(!IntervalSet::<i32>::new().intersects(0..10));
+    }
+}
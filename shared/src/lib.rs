@@ -1,61 +1,83 @@
-use clap::Parser;
-use std::collections::HashMap;
-use std::fmt::Debug;
+use std::process::exit;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-pub struct Args {
-    #[arg(short, long)]
-    pub input: String,
+mod answer;
+mod answers;
+mod args;
+mod bitset;
+mod counter;
+mod error;
+mod examples;
+mod graph;
+mod grid;
+mod input;
+mod interval;
+pub mod math;
+mod memo;
+mod parse;
+mod point;
+mod solution;
 
-    #[arg(short, long)]
-    pub problem: i32,
+pub use answer::*;
+pub use answers::*;
+pub use args::*;
+pub use bitset::*;
+pub use counter::*;
+pub use error::*;
+pub use examples::*;
+pub use graph::*;
+pub use grid::*;
+pub use input::*;
+pub use interval::*;
+pub use memo::*;
+pub use parse::*;
+pub use point::*;
+pub use shared_macros::{example_tests, memoize};
+pub use solution::*;
 
-    #[arg(short, long, action)]
-    pub debug: bool,
+static DEBUG: AtomicBool = AtomicBool::new(false);
+
+pub fn pretty_print_answer<T: Into<Answer>>(answer: T) {
+    println!("=====================");
+    println!("Answer: {}", answer.into());
+    println!("=====================");
 }
 
-impl Args {
-    pub fn argparse() -> Args {
-        Args::parse()
-    }
+/// Enable or disable debug output for solutions, see `debug_enabled`.
+pub fn set_debug(enabled: bool) {
+    DEBUG.store(enabled, Ordering::Relaxed);
 }
 
-pub struct Counter<T> {
-    counts: HashMap<T, usize>,
+/// Whether the `--debug` flag was passed to the binary running the solution.
+pub fn debug_enabled() -> bool {
+    DEBUG.load(Ordering::Relaxed)
 }
 
-impl<T> Counter<T>
-where
-    T: std::hash::Hash + Eq + Clone,
-{
-    pub fn new() -> Self {
-        Counter {
-            counts: HashMap::new(),
+/// Entrypoint for the per day binaries: read the input given in `Args` and solve the requested part.
+pub fn run_main<S: Solution>() {
+    let args = Args::argparse();
+    set_debug(args.debug);
+    let input = match args.input.read(S::DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err.render(None));
+            exit(1);
         }
-    }
-
-    pub fn add(&mut self, item: T) {
-        *self.counts.entry(item).or_insert(0) += 1;
-    }
-
-    pub fn remove(&mut self, item: &T) {
-        if let Some(count) = self.counts.get_mut(item) {
-            if *count > 1 {
-                *count -= 1;
-            } else {
-                self.counts.remove(item);
-            }
+    };
+    let parsed = match S::parse(&input.text) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err.with_file(input.name).render(Some(&input.text)));
+            exit(1);
         }
-    }
+    };
 
-    pub fn get(&self, item: &T) -> usize {
-        *self.counts.get(item).unwrap_or(&0)
+    match Part::try_from(args.problem) {
+        Ok(Part::One) => pretty_print_answer(S::part1(&parsed)),
+        Ok(Part::Two) => pretty_print_answer(S::part2(&parsed)),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            exit(1);
+        }
     }
 }
-
-pub fn pretty_print_answer<T: Debug>(answer: T) {
-    println!("=====================");
-    println!("Answer: {:?}", answer);
-    println!("=====================");
-}
@@ -0,0 +1,291 @@
+//! Number theory helpers.
+//!
+//! Functions which can overflow panic when they do, like the integer operators in debug
+//! builds, and have a `checked_` variant returning `None` instead.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// The primitive integer types, signed and unsigned.
+pub trait Int:
+    Copy
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TEN: Self = 10;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_neg(self) -> Option<Self> {
+                    <$t>::checked_neg(self)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+const OVERFLOW: &str = "attempt to compute with overflow";
+
+fn abs<T: Int>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        n.checked_neg()
+    } else {
+        Some(n)
+    }
+}
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Int>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect(OVERFLOW)
+}
+
+/// `gcd`, or `None` if the result does not fit into `T`, as for `gcd(i64::MIN, 0)`.
+pub fn checked_gcd<T: Int>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // only `MIN % -1` fails, whose result is 0
+        let r = a.checked_rem(b).unwrap_or(T::ZERO);
+        a = b;
+        b = r;
+    }
+    abs(a)
+}
+
+/// Least common multiple, never negative. It is 0 if either argument is 0.
+pub fn lcm<T: Int>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect(OVERFLOW)
+}
+
+pub fn checked_lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let g = checked_gcd(a, b)?;
+    abs(abs(a)? / g)?.checked_mul(abs(b)?)
+}
+
+/// Number of decimal digits of `n`, ignoring the sign. 0 has one digit.
+pub fn num_digits<T: Int>(mut n: T) -> u32 {
+    let mut digits = 1;
+    while n / T::TEN != T::ZERO {
+        n = n / T::TEN;
+        digits += 1;
+    }
+    digits
+}
+
+/// `10^exp`, or `None` if it does not fit into `T`.
+fn checked_pow10<T: Int>(exp: u32) -> Option<T> {
+    (0..exp).try_fold(T::ONE, |acc, _| acc.checked_mul(T::TEN))
+}
+
+/// Concatenate the decimal digits of `a` and `b`, so `concat(12, 345)` is 12345.
+/// Panics if either number is negative.
+pub fn concat<T: Int>(a: T, b: T) -> T {
+    assert!(
+        a >= T::ZERO && b >= T::ZERO,
+        "cannot concatenate negative numbers"
+    );
+    checked_concat(a, b).expect(OVERFLOW)
+}
+
+/// `concat`, or `None` if the result does not fit into `T` or either number is negative.
+pub fn checked_concat<T: Int>(a: T, b: T) -> Option<T> {
+    if a < T::ZERO || b < T::ZERO {
+        return None;
+    }
+    a.checked_mul(checked_pow10(num_digits(b))?)?.checked_add(b)
+}
+
+/// `(g, x, y)` such that `a * x + b * y = g`, where `g` is `gcd(a, b)`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    checked_extended_gcd(a, b).expect(OVERFLOW)
+}
+
+pub fn checked_extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (g, x, y) = extended_gcd_wide(a as i128, b as i128);
+    Some((g.try_into().ok()?, x.try_into().ok()?, y.try_into().ok()?))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `base^exp mod modulus`, in `0..modulus`. Panics if `modulus` is not positive.
+pub fn mod_pow(base: i64, mut exp: u64, modulus: i64) -> i64 {
+    assert!(modulus > 0, "modulus must be positive");
+    let modulus = modulus as i128;
+    let mut base = (base as i128).rem_euclid(modulus);
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as i64
+}
+
+/// `x` in `0..modulus` such that `a * x = 1 mod modulus`, `None` if `a` and `modulus` are
+/// not coprime. Panics if `modulus` is not positive.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "modulus must be positive");
+    let (g, x, _) = extended_gcd_wide(a as i128, modulus as i128);
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as i64)
+}
+
+/// Solve the system `x = residue mod modulus` for all `(residue, modulus)` pairs with the
+/// Chinese remainder theorem. The moduli need not be coprime.
+///
+/// Returns `(x, m)` such that the solutions are exactly `x + k * m` with `x` in `0..m`, or
+/// `None` if the congruences contradict each other. Panics if a modulus is not positive or
+/// `m` does not fit into `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    crt_wide(congruences).expect(OVERFLOW)
+}
+
+/// `crt`, also returning `None` if the combined modulus does not fit into `i64`.
+pub fn checked_crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    crt_wide(congruences).ok().flatten()
+}
+
+/// `Err` on overflow, `Ok(None)` if there is no solution.
+fn crt_wide(congruences: &[(i64, i64)]) -> Result<Option<(i64, i64)>, ()> {
+    let (mut x, mut m): (i128, i128) = (0, 1);
+    for &(residue, modulus) in congruences {
+        assert!(modulus > 0, "modulus must be positive");
+        let (residue, modulus) = (
+            (residue as i128).rem_euclid(modulus as i128),
+            modulus as i128,
+        );
+        // x + m * k = residue mod modulus, solvable iff g divides the difference
+        let (g, p, _) = extended_gcd_wide(m, modulus);
+        let diff = residue - x;
+        if diff % g != 0 {
+            return Ok(None);
+        }
+        let step = modulus / g;
+        let k = (diff / g % step * (p % step)).rem_euclid(step);
+        let combined = m * step;
+        if combined > i64::MAX as i128 {
+            return Err(());
+        }
+        x = (x + m * k).rem_euclid(combined);
+        m = combined;
+    }
+    Ok(Some((x as i64, m as i64)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 7u32), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(i64::MIN, -1), 1);
+        assert_eq!(checked_gcd(i64::MIN, 0), None);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(checked_lcm(u8::MAX, 2), None);
+    }
+
+    #[test]
+    fn digits_and_concat() {
+        assert_eq!(num_digits(0), 1);
+        assert_eq!(num_digits(9), 1);
+        assert_eq!(num_digits(10), 2);
+        assert_eq!(num_digits(-123), 3);
+        assert_eq!(num_digits(u64::MAX), 20);
+        assert_eq!(concat(12, 345), 12345);
+        assert_eq!(concat(0, 7), 7);
+        assert_eq!(concat(7, 0), 70);
+        assert_eq!(checked_concat(i64::MAX / 10, 10), None);
+        assert_eq!(checked_concat(255u8, 1), None);
+        assert_eq!(checked_concat(25u8, 5), Some(255));
+        assert_eq!(checked_concat(-1, 2), None);
+    }
+
+    #[test]
+    fn extended_gcd_coefficients() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 5), (0, 5), (5, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(checked_extended_gcd(i64::MIN, 0), None);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_pow(i64::MAX, u64::MAX, i64::MAX - 1), 1);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        let large = [(1, 1_000_000_007), (2, 998_244_353), (3, 1_000_000_009)];
+        assert_eq!(checked_crt(&large), None);
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Cache behind functions annotated with `#[memoize]`.
+///
+/// Without a capacity the cache grows without bound. With one, inserting into a full cache
+/// evicts the entry that was inserted first.
+#[derive(Clone, Debug)]
+pub struct MemoCache<K, V> {
+    values: HashMap<K, V>,
+    capacity: Option<usize>,
+    /// Insertion order of the keys, only tracked for bounded caches.
+    order: VecDeque<K>,
+}
+
+impl<K: Hash + Eq + Clone, V> MemoCache<K, V> {
+    pub fn new(capacity: Option<usize>) -> Self {
+        MemoCache {
+            values: HashMap::new(),
+            capacity,
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some(capacity) = self.capacity {
+            if capacity == 0 {
+                return;
+            }
+            if !self.values.contains_key(&key) {
+                if self.values.len() >= capacity {
+                    if let Some(oldest) = self.order.pop_front() {
+                        self.values.remove(&oldest);
+                    }
+                }
+                self.order.push_back(key.clone());
+            }
+        }
+        self.values.insert(key, value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unbounded_cache_keeps_everything() {
+        let mut cache = MemoCache::new(None);
+        assert!(cache.is_empty());
+        for i in 0..100 {
+            cache.insert(i, i * i);
+        }
+        assert_eq!(cache.len(), 100);
+        assert_eq!(cache.get(&7), Some(&49));
+        assert_eq!(cache.get(&100), None);
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&7), None);
+    }
+
+    #[test]
+    fn bounded_cache_evicts_the_oldest_entry() {
+        let mut cache = MemoCache::new(Some(2));
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        // overwriting an entry keeps its place in the eviction order
+        cache.insert("a", 10);
+        assert_eq!(cache.len(), 2);
+        cache.insert("c", 3);
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(&2));
+        assert_eq!(cache.get(&"c"), Some(&3));
+        cache.insert("d", 4);
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        cache.insert("e", 5);
+        cache.insert("f", 6);
+        cache.insert("g", 7);
+        assert_eq!((cache.get(&"e"), cache.len()), (None, 2));
+
+        let mut disabled = MemoCache::new(Some(0));
+        disabled.insert("a", 1);
+        assert!(disabled.is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{parse_token, AocError, AocResult};
+use std::fmt;
+use std::str::FromStr;
+
+/// Non-blank lines of `input` with their 1-based line numbers.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| (i + 1, line))
+}
+
+/// Parse every non-blank line of `input` with `f`, which gets the 1-based line number and the line.
+pub fn parse_lines<T, F>(input: &str, f: F) -> AocResult<Vec<T>>
+where
+    F: FnMut((usize, &str)) -> AocResult<T>,
+{
+    numbered_lines(input).map(f).collect()
+}
+
+/// A block of consecutive non-blank lines, see `sections`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    first_line: usize,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    /// 1-based line number of the first line of the section within the whole input.
+    pub fn line_no(&self) -> usize {
+        self.first_line
+    }
+
+    /// Lines of the section with their line numbers within the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        (self.first_line..).zip(self.lines.iter().copied())
+    }
+
+    /// Parse every line of the section with `f`, see `parse_lines`.
+    pub fn parse_lines<T, F>(&self, f: F) -> AocResult<Vec<T>>
+    where
+        F: FnMut((usize, &'a str)) -> AocResult<T>,
+    {
+        self.lines().map(f).collect()
+    }
+}
+
+/// Split `input` into sections separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut current: Option<Section> = None;
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            sections.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Section {
+                    first_line: i + 1,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+    }
+    sections.extend(current);
+    sections
+}
+
+/// All integers in `line`, ignoring anything around them. A `-` right before a digit is
+/// taken as the sign of the number.
+pub fn ints<T>(line_no: usize, line: &str) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let bytes = line.as_bytes();
+    let is_digit = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if is_digit(i) || (bytes[i] == b'-' && is_digit(i + 1)) {
+            let start = i;
+            i += 1;
+            while is_digit(i) {
+                i += 1;
+            }
+            numbers.push(parse_token(line_no, line, &line[start..i])?);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(numbers)
+}
+
+/// Parse the whitespace separated values of `line`.
+pub fn parse_words<T>(line_no: usize, line: &str) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    line.split_whitespace()
+        .map(|token| parse_token(line_no, line, token))
+        .collect()
+}
+
+/// Parse the values of `line` separated by `separator`, ignoring whitespace around them.
+pub fn parse_separated<T>(line_no: usize, line: &str, separator: &str) -> AocResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    line.split(separator)
+        .map(|token| parse_token(line_no, line, token.trim()))
+        .collect()
+}
+
+/// Parse a line of exactly `N` whitespace separated columns of the same type.
+pub fn parse_columns<T, const N: usize>(line_no: usize, line: &str) -> AocResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let values = parse_words::<T>(line_no, line)?;
+    let found = values.len();
+    values
+        .try_into()
+        .map_err(|_| AocError::at_line(line_no, format!("expected {} columns, found {}", N, found)))
+}
+
+/// Parse a line of two values of possibly different types, separated by `separator`.
+pub fn parse_pair<A, B>(line_no: usize, line: &str, separator: &str) -> AocResult<(A, B)>
+where
+    A: FromStr,
+    A::Err: fmt::Display,
+    B: FromStr,
+    B::Err: fmt::Display,
+{
+    let (a, b) = line.split_once(separator).ok_or_else(|| {
+        AocError::at_line(
+            line_no,
+            format!("expected two values separated by '{}'", separator),
+        )
+    })?;
+    Ok((
+        parse_token(line_no, line, a.trim())?,
+        parse_token(line_no, line, b.trim())?,
+    ))
+}
+
+/// Parse a `key: values` line, the values being separated by whitespace.
+pub fn parse_key_values<K, V>(line_no: usize, line: &str) -> AocResult<(K, Vec<V>)>
+where
+    K: FromStr,
+    K::Err: fmt::Display,
+    V: FromStr,
+    V::Err: fmt::Display,
+{
+    let (key, values) = line
+        .split_once(':')
+        .ok_or_else(|| AocError::at_line(line_no, "expected a line of the form 'key: values'"))?;
+    let key = parse_token(line_no, line, key.trim())?;
+    let values = values
+        .split_whitespace()
+        .map(|token| parse_token(line_no, line, token))
+        .collect::<AocResult<_>>()?;
+    Ok((key, values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_and_signs() {
+        assert_eq!(
+            ints::<i64>(1, "p=-3,12 v=4-5 --7 x-y 8-").unwrap(),
+            vec![-3, 12, 4, -5, -7, 8]
+        );
+        assert_eq!(ints::<i32>(1, "no numbers - here").unwrap(), vec![]);
+        let err = ints::<u32>(3, "a=1 b=-2").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(3), Some(7)));
+        assert!(ints::<u8>(1, "300").is_err());
+    }
+
+    #[test]
+    fn sections_keep_line_numbers() {
+        let input = "\na\nb\n\n\nc\n \nd\ne\n\n";
+        let sections = sections(input);
+        assert_eq!(sections.len(), 3);
+        assert_eq!(
+            sections.iter().map(Section::line_no).collect::<Vec<_>>(),
+            vec![2, 6, 8]
+        );
+        assert_eq!(
+            sections[2].lines().collect::<Vec<_>>(),
+            vec![(8, "d"), (9, "e")]
+        );
+        let err = sections[2]
+            .parse_lines(|(line_no, line)| parse_token::<u32>(line_no, line, line))
+            .unwrap_err();
+        assert_eq!(err.line, Some(8));
+        assert!(super::sections("\n \n").is_empty());
+        assert_eq!(
+            numbered_lines(input).collect::<Vec<_>>(),
+            vec![(2, "a"), (3, "b"), (6, "c"), (8, "d"), (9, "e")]
+        );
+    }
+
+    #[test]
+    fn line_parsers() {
+        assert_eq!(parse_columns::<i32, 2>(1, "3   4").unwrap(), [3, 4]);
+        let err = parse_columns::<i32, 2>(2, "3 4 5").unwrap_err();
+        assert_eq!(err.message, "expected 2 columns, found 3");
+        assert_eq!(
+            parse_separated::<u8>(1, "1, 2 ,3", ",").unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            parse_pair::<u8, String>(1, "47|x", "|").unwrap(),
+            (47, "x".to_string())
+        );
+        assert!(parse_pair::<u8, u8>(1, "47,53", "|").is_err());
+        assert_eq!(
+            parse_key_values::<i64, i64>(1, "190: 10 19").unwrap(),
+            (190, vec![10, 19])
+        );
+        let err = parse_key_values::<i64, i64>(4, "190: 10 x").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(4), Some(9)));
+    }
+}
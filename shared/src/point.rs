@@ -0,0 +1,358 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// A position on a grid, row 0 being the top row.
+///
+/// Coordinates are signed so positions outside of a grid can be represented, `Grid` checks
+/// the bounds when looking them up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// A displacement between two `Point`s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    /// Point of a `(row, col)` index into a grid.
+    pub const fn from_index(row: usize, col: usize) -> Self {
+        Point {
+            row: row as isize,
+            col: col as isize,
+        }
+    }
+
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: impl Into<Vec2>) -> Self {
+        self + direction.into()
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        (self - other).manhattan_length()
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Vec2 { row, col }
+    }
+
+    pub fn manhattan_length(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:+}, {:+})", self.row, self.col)
+    }
+}
+
+impl Add<Vec2> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vec2) -> Point {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub<Vec2> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vec2) -> Point {
+        Point::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Point) -> Vec2 {
+        Vec2::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl AddAssign<Vec2> for Point {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Vec2> for Point {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: isize) -> Vec2 {
+        Vec2::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+impl Div<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn div(self, rhs: isize) -> Vec2 {
+        Vec2::new(self.row / rhs, self.col / rhs)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.row, -self.col)
+    }
+}
+
+/// One of the four orthogonal directions on a grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn vector(self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(-1, 0),
+            Direction::Right => Vec2::new(0, 1),
+            Direction::Down => Vec2::new(1, 0),
+            Direction::Left => Vec2::new(0, -1),
+        }
+    }
+
+    /// Rotate 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Rotate 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Direction of an arrow character `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+}
+
+/// One of the eight orthogonal and diagonal directions on a grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting from up.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// The four diagonal directions, clockwise starting from up-right.
+    pub const DIAGONALS: [Dir8; 4] = [Dir8::UpRight, Dir8::DownRight, Dir8::DownLeft, Dir8::UpLeft];
+
+    pub fn vector(self) -> Vec2 {
+        match self {
+            Dir8::Up => Vec2::new(-1, 0),
+            Dir8::UpRight => Vec2::new(-1, 1),
+            Dir8::Right => Vec2::new(0, 1),
+            Dir8::DownRight => Vec2::new(1, 1),
+            Dir8::Down => Vec2::new(1, 0),
+            Dir8::DownLeft => Vec2::new(1, -1),
+            Dir8::Left => Vec2::new(0, -1),
+            Dir8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    /// Rotate 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotate 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Dir8 {
+    fn from(direction: Direction) -> Self {
+        Dir8::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        direction.vector()
+    }
+}
+
+impl From<Dir8> for Vec2 {
+    fn from(direction: Dir8) -> Self {
+        direction.vector()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direction_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Down.reverse(), Direction::Up);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(
+                direction.vector() + direction.reverse().vector(),
+                Vec2::ZERO
+            );
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+        }
+        assert_eq!(Direction::from_arrow('.'), None);
+        assert!(Direction::Down.is_vertical() && !Direction::Left.is_vertical());
+    }
+
+    #[test]
+    fn dir8_turns_and_conversion() {
+        assert_eq!(Dir8::Up.turn_right(), Dir8::UpRight);
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::UpRight.reverse(), Dir8::DownLeft);
+        for direction in Dir8::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(
+                direction.vector() + direction.reverse().vector(),
+                Vec2::ZERO
+            );
+        }
+        for direction in Dir8::DIAGONALS {
+            assert_eq!(direction.vector().manhattan_length(), 2);
+        }
+        for direction in Direction::ALL {
+            let dir8 = Dir8::from(direction);
+            assert_eq!(dir8.vector(), direction.vector());
+            assert_eq!(
+                dir8.turn_right().turn_right(),
+                direction.turn_right().into()
+            );
+        }
+        assert_eq!(Dir8::from(Direction::Left), Dir8::Left);
+    }
+
+    #[test]
+    fn point_arithmetic() {
+        let p = Point::new(2, 3);
+        assert_eq!(p.step(Direction::Up), Point::new(1, 3));
+        assert_eq!(p.step(Dir8::DownLeft), Point::new(3, 2));
+        assert_eq!(p - Point::new(5, 1), Vec2::new(-3, 2));
+        assert_eq!(p + Vec2::new(-3, 2) * 2, Point::new(-4, 7));
+        assert_eq!(-(Vec2::new(4, -2) / 2), Vec2::new(-2, 1));
+        assert_eq!(p.manhattan_distance(Point::new(-1, 5)), 5);
+        assert_eq!(Point::from_index(4, 1), Point::new(4, 1));
+        assert_eq!(p.to_string(), "(2, 3)");
+    }
+}
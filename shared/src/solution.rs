@@ -0,0 +1,163 @@
+use crate::{Answer, AocResult};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// One of the two parts every puzzle is split into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<i32> for Part {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            other => Err(format!("Part {} does not exist, expected 1 or 2", other)),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s
+            .parse::<i32>()
+            .map_err(|_| format!("Part must be a number, got '{}'", s))?;
+        Part::try_from(value)
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.number(), f)
+    }
+}
+
+/// A puzzle solution for a single day.
+///
+/// The input is parsed once with `parse` and the parsed value is shared by both parts.
+/// Malformed input is reported through `parse` as an `AocError` instead of panicking.
+pub trait Solution {
+    const DAY: u32;
+
+    type Input;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse(input: &str) -> AocResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
+
+/// Answers of one run of a day, with the time spent parsing the input.
+///
+/// Times are measured with the monotonic `Instant` clock.
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<PartAnswer>,
+}
+
+/// Answer to one part of a day, with the time it took to solve it from the parsed input.
+#[derive(Clone, Debug)]
+pub struct PartAnswer {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Object safe view of a `Solution`, so days with different input and output types
+/// can live in the same `Registry`.
+pub trait DynSolution {
+    fn day(&self) -> u32;
+
+    /// Parse `input` once and solve each of the requested `parts` with it.
+    fn run(&self, input: &str, parts: &[Part]) -> AocResult<DayRun>;
+}
+
+struct SolutionRunner<S: Solution>(std::marker::PhantomData<S>);
+
+impl<S: Solution> DynSolution for SolutionRunner<S> {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> AocResult<DayRun> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse_elapsed = start.elapsed();
+
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&parsed).into(),
+                    Part::Two => S::part2(&parsed).into(),
+                };
+                let elapsed = start.elapsed();
+                PartAnswer {
+                    part,
+                    answer,
+                    elapsed,
+                }
+            })
+            .collect();
+        Ok(DayRun {
+            parse_elapsed,
+            answers,
+        })
+    }
+}
+
+/// All known solutions, keyed by day.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u32, Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry {
+            days: BTreeMap::new(),
+        }
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self) {
+        let runner = SolutionRunner::<S>(std::marker::PhantomData);
+        if self.days.insert(S::DAY, Box::new(runner)).is_some() {
+            panic!("Day {} is registered twice", S::DAY);
+        }
+    }
+
+    pub fn get(&self, day: u32) -> Option<&dyn DynSolution> {
+        self.days.get(&day).map(|solution| solution.as_ref())
+    }
+
+    /// Registered days in ascending order.
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.days.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynSolution> {
+        self.days.values().map(|solution| solution.as_ref())
+    }
+}
@@ -0,0 +1,69 @@
+use shared::memoize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static FIB_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[memoize]
+fn fib(n: u64) -> u64 {
+    FIB_CALLS.fetch_add(1, Ordering::SeqCst);
+    if n < 2 {
+        n
+    } else {
+        fib(n - 1) + fib(n - 2)
+    }
+}
+
+#[test]
+fn recursive_calls_go_through_the_cache() {
+    assert_eq!(fib(80), 23416728348467685);
+    assert_eq!(FIB_CALLS.load(Ordering::SeqCst), 81);
+    assert_eq!(fib(80), 23416728348467685);
+    assert_eq!(fib(40), 102334155);
+    assert_eq!(FIB_CALLS.load(Ordering::SeqCst), 81);
+
+    fib_clear_cache();
+    assert_eq!(fib(10), 55);
+    assert_eq!(FIB_CALLS.load(Ordering::SeqCst), 92);
+}
+
+static SQUARE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[memoize(capacity = 2)]
+fn square(x: u32) -> u32 {
+    SQUARE_CALLS.fetch_add(1, Ordering::SeqCst);
+    x * x
+}
+
+#[test]
+fn bounded_cache_evicts_the_oldest_result() {
+    let calls = || SQUARE_CALLS.load(Ordering::SeqCst);
+    assert_eq!((square(1), square(2), square(1)), (1, 4, 1));
+    assert_eq!(calls(), 2);
+    assert_eq!(square(3), 9);
+    assert_eq!(calls(), 3);
+    // 1 was evicted, 2 was not
+    assert_eq!((square(2), calls()), (4, 3));
+    assert_eq!((square(1), calls()), (1, 4));
+}
+
+/// Number of steps of the Collatz sequence from `n` to 1.
+#[memoize]
+pub fn collatz_steps(mut n: u64, label: String) -> (String, usize) {
+    let mut steps = 0;
+    while n != 1 {
+        n = if n.is_multiple_of(2) {
+            n / 2
+        } else {
+            3 * n + 1
+        };
+        steps += 1;
+    }
+    (label, steps)
+}
+
+#[test]
+fn arguments_may_be_mut_and_owned() {
+    assert_eq!(collatz_steps(27, "a".to_string()), ("a".to_string(), 111));
+    assert_eq!(collatz_steps(27, "b".to_string()), ("b".to_string(), 111));
+    assert_eq!(collatz_steps(1, String::new()), (String::new(), 0));
+}
@@ -0,0 +1,395 @@
+//! Procedural macros re-exported by `shared`.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use std::fs;
+use std::io;
+use std::path::Path;
+use syn::{parse_macro_input, Error, FnArg, ItemFn, LitInt, Pat, ReturnType, Type};
+
+/// Cache the results of a free function by its arguments.
+///
+/// The arguments must be owned values implementing `Clone + Hash + Eq + Send` and the
+/// return value must implement `Clone + Send`. The cache is shared between threads.
+/// Recursive calls go through the cache too, which is what makes memoizing recursive
+/// functions worthwhile.
+///
+/// `#[memoize(capacity = N)]` bounds the cache to `N` entries, evicting the oldest entry
+/// when full. For a function `f`, the macro also defines `f_clear_cache()` with the same
+/// visibility, which empties the cache, for example between the two parts of a puzzle.
+#[proc_macro_attribute]
+pub fn memoize(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut capacity: Option<LitInt> = None;
+    let attr_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("capacity") {
+            capacity = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported memoize option, expected `capacity = N`"))
+        }
+    });
+    parse_macro_input!(attr with attr_parser);
+    let function = parse_macro_input!(item as ItemFn);
+    match expand(function, capacity) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(function: ItemFn, capacity: Option<LitInt>) -> syn::Result<proc_macro2::TokenStream> {
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = function;
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &sig.generics,
+            "memoize does not support generic functions",
+        ));
+    }
+    if sig.asyncness.is_some() {
+        return Err(Error::new_spanned(
+            sig.asyncness,
+            "memoize does not support async functions",
+        ));
+    }
+    let ReturnType::Type(_, output) = &sig.output else {
+        return Err(Error::new_spanned(
+            &sig,
+            "memoize needs a function returning a value",
+        ));
+    };
+
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    for input in &sig.inputs {
+        let FnArg::Typed(arg) = input else {
+            return Err(Error::new_spanned(
+                input,
+                "memoize only supports free functions",
+            ));
+        };
+        let Pat::Ident(pat) = &*arg.pat else {
+            return Err(Error::new_spanned(
+                &arg.pat,
+                "memoize needs plain argument names",
+            ));
+        };
+        if pat.by_ref.is_some() || pat.subpat.is_some() {
+            return Err(Error::new_spanned(
+                &arg.pat,
+                "memoize needs plain argument names",
+            ));
+        }
+        names.push(pat.ident.clone());
+        types.push((*arg.ty).clone());
+    }
+
+    let name = &sig.ident;
+    let cache = format_ident!("__{}_MEMOIZE_CACHE", name.to_string().to_uppercase());
+    let clear = format_ident!("{}_clear_cache", name);
+    let capacity = match capacity {
+        Some(capacity) => quote!(::std::option::Option::Some(#capacity)),
+        None => quote!(::std::option::Option::None),
+    };
+
+    // The original function is kept as an inner function. The outer signature drops `mut`
+    // from the arguments since only the inner function uses them.
+    let mut inner_sig = sig.clone();
+    inner_sig.ident = format_ident!("__{}_uncached", name);
+    let inner = &inner_sig.ident;
+    let mut outer_sig = sig.clone();
+    for input in outer_sig.inputs.iter_mut() {
+        if let FnArg::Typed(arg) = input {
+            if let Pat::Ident(pat) = &mut *arg.pat {
+                pat.mutability = None;
+            }
+        }
+    }
+
+    Ok(quote! {
+        #[allow(non_upper_case_globals)]
+        static #cache: ::std::sync::LazyLock<
+            ::std::sync::Mutex<::shared::MemoCache<(#(#types,)*), #output>>,
+        > = ::std::sync::LazyLock::new(|| {
+            ::std::sync::Mutex::new(::shared::MemoCache::new(#capacity))
+        });
+
+        #(#attrs)*
+        #vis #outer_sig {
+            #inner_sig #block
+
+            let __memoize_key = (#(::std::clone::Clone::clone(&#names),)*);
+            if let ::std::option::Option::Some(__memoize_value) = #cache
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner)
+                .get(&__memoize_key)
+            {
+                return ::std::clone::Clone::clone(__memoize_value);
+            }
+            let __memoize_value = #inner(#(#names),*);
+            #cache
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner)
+                .insert(__memoize_key, ::std::clone::Clone::clone(&__memoize_value));
+            __memoize_value
+        }
+
+        /// Empty the cache of the memoized function of the same name.
+        #[allow(dead_code)]
+        #vis fn #clear() {
+            #cache
+                .lock()
+                .unwrap_or_else(::std::sync::PoisonError::into_inner)
+                .clear();
+        }
+    })
+}
+
+/// Generate a test for every part of every stored example of the day, checking the answer
+/// of the solution against `answers.toml`:
+///
+/// ```ignore
+/// shared::example_tests!(Day6);
+/// ```
+///
+/// The day is taken from the directory of the crate, like `day6`, and the examples are
+/// looked up in `examples/day6/` of the workspace. `examples/day6/example2.txt` becomes the
+/// tests `example2_part1` and `example2_part2`. Tests of parts without an answer are
+/// ignored, and so is a placeholder test when the day has no examples at all.
+///
+/// Changes to `answers.toml` and the example files rebuild the tests. A new example file is
+/// only picked up once its answers are added, or after `cargo clean -p dayN`.
+#[proc_macro]
+pub fn example_tests(input: TokenStream) -> TokenStream {
+    let solution = parse_macro_input!(input as Type);
+    match expand_example_tests(solution) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn error(message: String) -> Error {
+    Error::new(Span::call_site(), message)
+}
+
+/// Contents of `path`, `None` if it does not exist.
+fn read_optional(path: &Path) -> syn::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(error(format!("failed to read {}: {}", path.display(), err))),
+    }
+}
+
+/// Names of the examples in `dir`, the stems of its `.txt` files.
+fn example_names(dir: &Path) -> syn::Result<Vec<String>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(error(format!("failed to read {}: {}", dir.display(), err))),
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Name of the test of `part` of the example `name`, which may contain any character.
+fn test_name(name: &str, part: u32) -> proc_macro2::Ident {
+    let mut ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !ident.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        ident.insert_str(0, "example_");
+    }
+    format_ident!("{}_part{}", ident, part)
+}
+
+fn expand_example_tests(solution: Type) -> syn::Result<proc_macro2::TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| error("CARGO_MANIFEST_DIR is not set".to_string()))?;
+    let manifest_dir = Path::new(&manifest_dir);
+    let day: u32 = manifest_dir
+        .file_name()
+        .and_then(|name| name.to_str()?.strip_prefix("day")?.parse().ok())
+        .ok_or_else(|| {
+            error(format!(
+                "example_tests! needs a day crate in a directory like `day6`, not {}",
+                manifest_dir.display()
+            ))
+        })?;
+    let root = manifest_dir
+        .parent()
+        .ok_or_else(|| error("day crates live in the workspace root".to_string()))?;
+
+    let answers_path = root.join("answers.toml");
+    let answers_text = read_optional(&answers_path)?;
+    let answers: toml::Table = match &answers_text {
+        Some(text) => text
+            .parse()
+            .map_err(|err| error(format!("{}: {}", answers_path.display(), err)))?,
+        None => toml::Table::new(),
+    };
+    let day_answers = answers.get(&format!("day{}", day));
+    let examples_dir = root.join("examples").join(format!("day{}", day));
+    let names = example_names(&examples_dir)?;
+
+    // Include the files read above, so cargo rebuilds the tests when they change
+    let mut tracked: Vec<String> = names
+        .iter()
+        .map(|name| {
+            let path = examples_dir.join(format!("{}.txt", name));
+            path.display().to_string()
+        })
+        .collect();
+    if answers_text.is_some() {
+        tracked.push(answers_path.display().to_string());
+    }
+
+    let mut tests = Vec::new();
+    for name in &names {
+        for (part, number) in [(quote!(One), 1), (quote!(Two), 2)] {
+            let test = test_name(name, number);
+            let has_answer = day_answers
+                .and_then(|examples| examples.get(name))
+                .and_then(|answers| answers.get(format!("part{}", number)))
+                .is_some();
+            let ignore = (!has_answer).then(|| {
+                let reason = format!(
+                    "no answer for part {} of day{}.{} in answers.toml",
+                    number, day, name
+                );
+                quote!(#[ignore = #reason])
+            });
+            tests.push(quote! {
+                #[test]
+                #ignore
+                fn #test() {
+                    ::shared::check_example::<#solution>(
+                        env!("CARGO_MANIFEST_DIR"),
+                        #name,
+                        ::shared::Part::#part,
+                    );
+                }
+            });
+        }
+    }
+    if names.is_empty() {
+        let reason = format!("no examples in examples/day{}", day);
+        tests.push(quote! {
+            #[test]
+            #[ignore = #reason]
+            fn no_examples() {
+                panic!(#reason);
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[cfg(test)]
+        mod example_tests {
+            use super::*;
+
+            #(const _: &str = include_str!(#tracked);)*
+
+            #(#tests)*
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn expand_error(function: ItemFn) -> String {
+        expand(function, None).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn memoize_rejects_unsupported_functions() {
+        assert_eq!(
+            expand_error(parse_quote!(
+                fn f<T>(x: T) -> T {
+                    x
+                }
+            )),
+            "memoize does not support generic functions"
+        );
+        assert_eq!(
+            expand_error(parse_quote!(
+                async fn f(x: u32) -> u32 {
+                    x
+                }
+            )),
+            "memoize does not support async functions"
+        );
+        assert_eq!(
+            expand_error(parse_quote!(
+                fn f(&self, x: u32) -> u32 {
+                    x
+                }
+            )),
+            "memoize only supports free functions"
+        );
+        assert_eq!(
+            expand_error(parse_quote!(
+                fn f(x: u32) {}
+            )),
+            "memoize needs a function returning a value"
+        );
+        assert_eq!(
+            expand_error(parse_quote!(
+                fn f((a, b): (u32, u32)) -> u32 {
+                    a + b
+                }
+            )),
+            "memoize needs plain argument names"
+        );
+        assert_eq!(
+            expand_error(parse_quote!(
+                fn f(ref x: u32) -> u32 {
+                    *x
+                }
+            )),
+            "memoize needs plain argument names"
+        );
+    }
+
+    #[test]
+    fn memoize_keeps_mut_arguments_to_the_inner_function() {
+        let function: ItemFn = parse_quote!(
+            pub fn f(mut n: u64) -> u64 {
+                n += 1;
+                n
+            }
+        );
+        let expanded = expand(function, Some(parse_quote!(3))).unwrap().to_string();
+        assert!(
+            expanded.contains("pub fn f (n : u64) -> u64"),
+            "{}",
+            expanded
+        );
+        assert!(
+            expanded.contains("fn __f_uncached (mut n : u64)"),
+            "{}",
+            expanded
+        );
+        assert!(expanded.contains("pub fn f_clear_cache ()"), "{}", expanded);
+        assert!(expanded.contains("Some (3)"), "{}", expanded);
+    }
+}